@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use bevy_proto_resource_tuples::*;
+use bevy_proto_resource_tuples::prelude::*;
 
 #[derive(Resource)]
 struct ResourceA;
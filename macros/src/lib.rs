@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{Ident, Index};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Index, Member};
 
 fn get_idents(fmt_string: fn(usize) -> String, count: usize) -> Vec<Ident> {
     (0..count)
@@ -9,31 +9,862 @@ fn get_idents(fmt_string: fn(usize) -> String, count: usize) -> Vec<Ident> {
         .collect::<Vec<Ident>>()
 }
 
+/// Panics in debug builds if any of the resource types `ty` appears more than once.
+fn debug_assert_distinct(ty: &[Ident]) -> proc_macro2::TokenStream {
+    quote! {
+        #[cfg(debug_assertions)]
+        assert_distinct_resources(
+            &[#(std::any::TypeId::of::<#ty>(),)*],
+            &[#(std::any::type_name::<#ty>(),)*],
+        );
+    }
+}
+
+/// Appends the [`TypeId`] and type name of each resource type in `ty`, in order.
+fn resource_types(ty: &[Ident]) -> proc_macro2::TokenStream {
+    quote! {
+        fn resource_types(types: &mut Vec<(TypeId, &'static str)>) {
+            #(types.push((TypeId::of::<#ty>(), std::any::type_name::<#ty>()));)*
+        }
+    }
+}
+
 #[proc_macro]
 pub fn impl_resource_apis(_input: TokenStream) -> TokenStream {
     let mut tokens = TokenStream::new();
     let max_types = 16;
     let types = get_idents(|i| format!("P{i}"), max_types);
+    let fn_types = get_idents(|i| format!("F{i}"), max_types);
 
     for i in 1..=max_types {
         let ty = &types[0..i];
+        let fn_ty = &fn_types[0..i];
         let indices = (0..i).map(Index::from).collect::<Vec<_>>();
+        let component_id_tys = vec![quote!(ComponentId); i];
+        let component_ticks_tys = vec![quote!(ComponentTicks); i];
+        let ptr_tys = vec![quote!(Option<Ptr<'w>>); i];
+        let rev_ty = ty.iter().rev();
+        let debug_assert_distinct = debug_assert_distinct(ty);
+        let resource_types = resource_types(ty);
         tokens.extend(TokenStream::from(quote! {
             impl<#(#ty: Resource + FromWorld,)*> InitResources for (#(#ty,)*) {
+                const LEN: usize = #i;
+
                 type IDS = [ComponentId; #i];
+                type Report = [bool; #i];
+                type TypeNames = [&'static str; #i];
+                type TypeIds = [TypeId; #i];
 
+                #[inline]
+                #[track_caller]
                 fn init_resources(world: &mut World) -> Self::IDS {
-                    [#(world.init_resource::<#ty>(),)*]
+                    #debug_assert_distinct
+                    [#(init_resource_checked::<#ty>(world),)*]
+                }
+
+                #[track_caller]
+                fn init_resources_verbose(world: &mut World) -> Self::IDS {
+                    #debug_assert_distinct
+                    [#({
+                        warn_if_present::<#ty>(world);
+                        init_resource_checked::<#ty>(world)
+                    },)*]
+                }
+
+                #[track_caller]
+                fn init_resources_report(world: &mut World) -> Self::Report {
+                    #debug_assert_distinct
+                    [#({
+                        let created = !world.contains_resource::<#ty>();
+                        init_resource_checked::<#ty>(world);
+                        created
+                    },)*]
+                }
+
+                #[track_caller]
+                fn init_resources_map(world: &mut World) -> HashMap<TypeId, ComponentId> {
+                    #debug_assert_distinct
+                    HashMap::from([#((TypeId::of::<#ty>(), init_resource_checked::<#ty>(world)),)*])
+                }
+
+                fn type_names() -> Self::TypeNames {
+                    [#(std::any::type_name::<#ty>(),)*]
+                }
+
+                fn type_ids() -> Self::TypeIds {
+                    [#(TypeId::of::<#ty>(),)*]
+                }
+
+                #resource_types
+
+                fn index_of<T: 'static>() -> Option<usize> {
+                    let id = TypeId::of::<T>();
+                    #(
+                        if id == TypeId::of::<#ty>() {
+                            return Some(#indices);
+                        }
+                    )*
+                    None
+                }
+
+                fn register_resource_ids(world: &mut World) {
+                    #(register_resource_id::<#ty>(world);)*
+                }
+            }
+
+            impl<#(#ty: Resource + FromWorld,)*> InitResourcesTyped for (#(#ty,)*) {
+                type TypedIds = (#(ResourceId<#ty>,)*);
+
+                #[track_caller]
+                fn init_resources_typed(world: &mut World) -> Self::TypedIds {
+                    #debug_assert_distinct
+                    (#(ResourceId::new(init_resource_checked::<#ty>(world)),)*)
+                }
+
+                #resource_types
+            }
+
+            impl<E, #(#ty: Resource + TryFromWorld<Error = E>,)*> TryInitResources for (#(#ty,)*) {
+                type IDS = [ComponentId; #i];
+                type Error = E;
+
+                #[track_caller]
+                fn try_init_resources(world: &mut World) -> Result<Self::IDS, Self::Error> {
+                    #debug_assert_distinct
+                    let mut created = [false; #i];
+                    let rollback = |world: &mut World, created: &[bool; #i]| {
+                        #(
+                            if created[#indices] {
+                                world.remove_resource::<#ty>();
+                            }
+                        )*
+                    };
+                    Ok([#({
+                        if !world.contains_resource::<#ty>() {
+                            match #ty::try_from_world(world) {
+                                Ok(resource) => {
+                                    world.insert_resource(resource);
+                                    created[#indices] = true;
+                                }
+                                Err(error) => {
+                                    rollback(world, &created);
+                                    return Err(error);
+                                }
+                            }
+                        }
+                        world.components().resource_id::<#ty>().unwrap()
+                    },)*])
+                }
+
+                #resource_types
+            }
+
+            impl<#(#ty: Resource + Default,)*> DefaultResources for (#(#ty,)*) {}
+
+            impl<#(#ty: Resource + FromWorld,)*> InitResourcesMut for (#(#ty,)*) {
+                type Muts<'w> = (#(Mut<'w, #ty>,)*);
+
+                #[track_caller]
+                fn init_resources_mut(world: &mut World) -> Self::Muts<'_> {
+                    let ids = Self::init_resources(world);
+                    assert_distinct_resources(&ids, &[#(std::any::type_name::<#ty>(),)*]);
+
+                    let world = world.as_unsafe_world_cell();
+                    // SAFETY: the resources are distinct, so each one is only borrowed mutably once.
+                    unsafe {
+                        (#(world
+                            .get_resource_mut::<#ty>()
+                            .expect("resource was removed while initializing the other resources"),)*)
+                    }
                 }
             }
 
             impl<#(#ty: Resource,)*> InsertResources for (#(#ty,)*) {
-                fn insert_resources(self, world: &mut World) {
-                    #(world.insert_resource(self.#indices);)*
+                const LEN: usize = #i;
+
+                type IDS = [ComponentId; #i];
+                type Inserted = [bool; #i];
+                type TypeIds = [TypeId; #i];
+
+                #[inline]
+                #[track_caller]
+                fn insert_resources(self, world: &mut World) -> Self::IDS {
+                    #debug_assert_distinct
+                    [#({
+                        world.insert_resource(self.#indices);
+                        world.components().resource_id::<#ty>().unwrap()
+                    },)*]
+                }
+
+                #[track_caller]
+                fn insert_resources_report(self, world: &mut World) -> Self::Inserted {
+                    #debug_assert_distinct
+                    [#({
+                        let existed = world.contains_resource::<#ty>();
+                        world.insert_resource(self.#indices);
+                        existed
+                    },)*]
+                }
+
+                #[track_caller]
+                fn insert_resources_defer_drop(self, world: &mut World, drops: &mut DeferredDrops) -> Self::IDS {
+                    #debug_assert_distinct
+                    [#(insert_deferring_drop(world, self.#indices, drops),)*]
+                }
+
+                fn insert_resources_dedup(self, world: &mut World) -> Self::IDS {
+                    #[cfg(debug_assertions)]
+                    warn_duplicate_resources(
+                        &[#(std::any::TypeId::of::<#ty>(),)*],
+                        &[#(std::any::type_name::<#ty>(),)*],
+                    );
+                    [#({
+                        world.insert_resource(self.#indices);
+                        world.components().resource_id::<#ty>().unwrap()
+                    },)*]
+                }
+
+                #[track_caller]
+                fn insert_resources_if_absent(self, world: &mut World) -> Self::Inserted {
+                    #debug_assert_distinct
+                    [#({
+                        let absent = !world.contains_resource::<#ty>();
+                        if absent {
+                            world.insert_resource(self.#indices);
+                        } else {
+                            drop_resource(self.#indices);
+                        }
+                        absent
+                    },)*]
+                }
+
+                fn type_ids() -> Self::TypeIds {
+                    [#(TypeId::of::<#ty>(),)*]
+                }
+
+                #resource_types
+
+                fn existing_type_names(world: &World, type_names: &mut Vec<&'static str>) {
+                    #(
+                        if world.contains_resource::<#ty>() {
+                            type_names.push(std::any::type_name::<#ty>());
+                        }
+                    )*
+                }
+            }
+
+            impl<#(#ty: Resource,)*> InsertBoxedResources for (#(Box<#ty>,)*) {
+                type IDS = [ComponentId; #i];
+
+                fn insert_boxed_resources(self, world: &mut World) -> Self::IDS {
+                    (#(*self.#indices,)*).insert_resources(world)
+                }
+
+                #resource_types
+            }
+
+            impl<'a, #(#ty: Resource + Clone,)*> InsertCowResources for (#(Cow<'a, #ty>,)*) {
+                type IDS = [ComponentId; #i];
+
+                fn insert_cow_resources(self, world: &mut World) -> Self::IDS {
+                    (#(self.#indices.into_owned(),)*).insert_resources(world)
+                }
+
+                #resource_types
+            }
+
+            impl<E, #(#ty: Resource,)*> InsertResultResources<E> for (#(Result<#ty, E>,)*) {
+                type IDS = [ComponentId; #i];
+
+                fn insert_result_resources(self, world: &mut World) -> Result<Self::IDS, E> {
+                    let resources = (#(self.#indices?,)*);
+                    Ok(resources.insert_resources(world))
+                }
+
+                #resource_types
+            }
+
+            impl<#(#ty: Resource,)*> InsertOptionalResources for (#(Option<#ty>,)*) {
+                type IDS = (#(Option<#component_id_tys>,)*);
+
+                fn insert_optional_resources(self, world: &mut World) -> Self::IDS {
+                    (#(self.#indices.map(|resource| {
+                        world.insert_resource(resource);
+                        world.components().resource_id::<#ty>().unwrap()
+                    }),)*)
+                }
+
+                fn resource_types(&self, types: &mut Vec<(TypeId, &'static str)>) {
+                    #(
+                        if self.#indices.is_some() {
+                            types.push((TypeId::of::<#ty>(), std::any::type_name::<#ty>()));
+                        }
+                    )*
+                }
+            }
+
+            impl<#(#ty: Resource,)*> GetResources for (#(#ty,)*) {
+                type Refs<'w> = (#(&'w #ty,)*);
+
+                fn get_resources(world: &World) -> Option<Self::Refs<'_>> {
+                    Some((#(world.get_resource::<#ty>()?,)*))
+                }
+            }
+
+            impl<#(#ty: Resource + Default,)*> ResetResources for (#(#ty,)*) {
+                type Reset = [bool; #i];
+
+                fn reset_resources(world: &mut World) -> Self::Reset {
+                    #debug_assert_distinct
+                    [#({
+                        let existed = world.contains_resource::<#ty>();
+                        replace_resource(world, #ty::default());
+                        existed
+                    },)*]
+                }
+
+                #resource_types
+            }
+
+            impl<#(#ty: Resource,)*> ResourcePtrs for (#(#ty,)*) {
+                type Ptrs<'w> = (#(#ptr_tys,)*);
+
+                fn resource_ptrs(world: &World) -> Self::Ptrs<'_> {
+                    (#(world
+                        .components()
+                        .resource_id::<#ty>()
+                        .and_then(|id| world.get_resource_by_id(id)),)*)
+                }
+            }
+
+            impl<#(#ty: Resource,)*> InsertResourcesWithPolicy for (#(#ty,)*) {
+                type Outcomes = (#(InsertOutcome<#ty>,)*);
+
+                #[track_caller]
+                fn insert_resources_with_policy(
+                    self,
+                    world: &mut World,
+                    policy: OverwritePolicy,
+                ) -> Result<Self::Outcomes, InsertError> {
+                    #debug_assert_distinct
+                    if policy == OverwritePolicy::Error {
+                        let mut type_names = Vec::new();
+                        <Self as InsertResources>::existing_type_names(world, &mut type_names);
+                        if !type_names.is_empty() {
+                            return Err(InsertError { type_names });
+                        }
+                    }
+                    Ok((#(insert_with_policy(world, self.#indices, policy),)*))
+                }
+            }
+
+            impl<#(#ty: Resource + FromWorld,)*> InsertOrInitResources for (#(OrDefault<#ty>,)*) {
+                type IDS = [ComponentId; #i];
+
+                #[track_caller]
+                fn insert_or_init_resources(self, world: &mut World) -> Self::IDS {
+                    #debug_assert_distinct
+                    [#(match self.#indices {
+                        OrDefault::Value(resource) => {
+                            world.insert_resource(resource);
+                            world.components().resource_id::<#ty>().unwrap()
+                        }
+                        OrDefault::Default => world.init_resource::<#ty>(),
+                    },)*]
+                }
+
+                #resource_types
+            }
+
+            #[cfg(feature = "timing")]
+            impl<#(#ty: Resource + FromWorld,)*> InitResourcesTimed for (#(#ty,)*) {
+                type Durations = [std::time::Duration; #i];
+
+                #[track_caller]
+                fn init_resources_timed(world: &mut World) -> Self::Durations {
+                    #debug_assert_distinct
+                    [#(init_resource_timed::<#ty>(world),)*]
+                }
+
+                #resource_types
+            }
+
+            #[cfg(feature = "reflect")]
+            impl<#(#ty: Resource,)*> ReflectResources for (#(#ty,)*) {
+                fn clone_resources_reflect(
+                    world: &World,
+                    registry: &bevy_reflect::TypeRegistry,
+                ) -> ReflectedResources {
+                    let mut reflected = ReflectedResources::default();
+                    #(reflected.push::<#ty>(world, registry);)*
+                    reflected
+                }
+            }
+
+            #[cfg(feature = "reflect")]
+            impl<#(#ty: Resource + FromWorld + bevy_reflect::GetTypeRegistration,)*> RegisterResources
+                for (#(#ty,)*)
+            {
+                fn register_resources(registry: &mut bevy_reflect::TypeRegistry) {
+                    #(registry.register::<#ty>();)*
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<#(#ty: Resource + serde::Serialize,)*> SerializeResources for (#(#ty,)*) {
+                fn serialize_resources(world: &World) -> Result<String, SerializeResourcesError> {
+                    let resources = (#(world
+                        .get_resource::<#ty>()
+                        .ok_or(SerializeResourcesError::MissingResource(std::any::type_name::<#ty>()))?,)*);
+                    Ok(ron::to_string(&resources)?)
+                }
+            }
+
+            impl<#(#ty: Resource + Clone,)*> CloneResources for (#(#ty,)*) {
+                fn clone_resources_into(world: &World, target: &mut World) {
+                    #(
+                        if let Some(resource) = world.get_resource::<#ty>() {
+                            target.insert_resource(resource.clone());
+                        }
+                    )*
+                }
+
+                fn snapshot_resources(world: &World) -> Option<Self> {
+                    Some((#(world.get_resource::<#ty>()?.clone(),)*))
+                }
+
+                fn clone_resources_into_if_absent(world: &World, target: &mut World) {
+                    #(
+                        if !target.contains_resource::<#ty>() {
+                            if let Some(resource) = world.get_resource::<#ty>() {
+                                target.insert_resource(resource.clone());
+                            }
+                        }
+                    )*
+                }
+
+                #resource_types
+            }
+
+            impl<#(#ty: Resource,)*> ResourceChangeTicks for (#(#ty,)*) {
+                type Ticks = (#(Option<#component_ticks_tys>,)*);
+                type Changed = [bool; #i];
+
+                fn resource_change_ticks(world: &World) -> Self::Ticks {
+                    (#(world
+                        .components()
+                        .resource_id::<#ty>()
+                        .and_then(|id| world.storages().resources.get(id))
+                        .and_then(|data| data.get_ticks()),)*)
+                }
+
+                fn resources_changed(
+                    world: &World,
+                    last_change_tick: u32,
+                    change_tick: u32,
+                ) -> Self::Changed {
+                    [#(world
+                        .components()
+                        .resource_id::<#ty>()
+                        .and_then(|id| world.storages().resources.get(id))
+                        .and_then(|data| data.get_ticks())
+                        .map_or(false, |ticks| ticks.is_changed(last_change_tick, change_tick)),)*]
+                }
+
+                fn resources_added_since(world: &World, since: u32, change_tick: u32) -> Self::Changed {
+                    [#(world
+                        .components()
+                        .resource_id::<#ty>()
+                        .and_then(|id| world.storages().resources.get(id))
+                        .and_then(|data| data.get_ticks())
+                        .map_or(false, |ticks| ticks.is_added(since, change_tick)),)*]
+                }
+            }
+
+            impl<#(#ty: Resource,)* #(#fn_ty: FnOnce(#ty) -> #ty,)*> MapResources<(#(#fn_ty,)*)>
+                for (#(#ty,)*)
+            {
+                type Mapped = [bool; #i];
+
+                fn map_resources(world: &mut World, f: (#(#fn_ty,)*)) -> Self::Mapped {
+                    [#(match world.remove_resource::<#ty>() {
+                        Some(resource) => {
+                            world.insert_resource((f.#indices)(resource));
+                            true
+                        }
+                        None => false,
+                    },)*]
+                }
+            }
+
+            impl<V, #(#ty: Resource,)*> VisitResources<V> for (#(#ty,)*)
+            where
+                #(V: VisitResource<#ty>,)*
+            {
+                fn visit_resources(world: &World, visitor: &mut V) {
+                    #(
+                        if let Some(resource) = world.get_resource::<#ty>() {
+                            visitor.visit(resource);
+                        }
+                    )*
+                }
+            }
+
+            impl<V, #(#ty: Resource,)*> VisitResourcesMut<V> for (#(#ty,)*)
+            where
+                #(V: VisitResourceMut<#ty>,)*
+            {
+                fn visit_resources_mut(world: &mut World, visitor: &mut V) {
+                    #(
+                        if let Some(resource) = world.get_resource_mut::<#ty>() {
+                            visitor.visit_mut(resource);
+                        }
+                    )*
+                }
+            }
+
+            impl<#(#ty: Resource,)*> ContainsResources for (#(#ty,)*) {
+                type Contains = [bool; #i];
+
+                fn contains_resources(world: &World) -> Self::Contains {
+                    [#(world.contains_resource::<#ty>(),)*]
+                }
+            }
+
+            impl<#(#ty: Resource,)*> ResourceIds for (#(#ty,)*) {
+                type IDS = (#(Option<#component_id_tys>,)*);
+                type IdSlots = [Option<ComponentId>; #i];
+
+                fn resource_ids(world: &World) -> Self::IDS {
+                    (#(world.components().resource_id::<#ty>(),)*)
+                }
+
+                fn resource_id_slots(world: &World) -> Self::IdSlots {
+                    [#(world.components().resource_id::<#ty>(),)*]
+                }
+            }
+
+            impl<#(#ty: Resource,)*> RemoveResources for (#(#ty,)*) {
+                type Partial = (#(Option<#ty>,)*);
+                type Removed = [bool; #i];
+
+                fn remove_resources(world: &mut World) {
+                    #(world.remove_resource::<#ty>();)*
+                }
+
+                fn remove_resources_bool(world: &mut World) -> Self::Removed {
+                    [#(world.remove_resource::<#ty>().is_some(),)*]
+                }
+
+                fn remove_resources_in_order(world: &mut World) {
+                    #(drop(world.remove_resource::<#rev_ty>());)*
+                }
+
+                fn take_resources(world: &mut World) -> Option<Self> {
+                    if #(world.contains_resource::<#ty>())&&* {
+                        Some((#(world.remove_resource::<#ty>().unwrap(),)*))
+                    } else {
+                        None
+                    }
+                }
+
+                fn try_take_resources(world: &mut World) -> Self::Partial {
+                    (#(world.remove_resource::<#ty>(),)*)
                 }
             }
         }));
     }
 
+    // Every combination of values and `Fill` markers is its own impl, so this is kept to small tuples.
+    let max_fill_types = 4;
+    for i in 1..=max_fill_types {
+        let ty = &types[0..i];
+        let debug_assert_distinct = debug_assert_distinct(ty);
+        let resource_types = resource_types(ty);
+        for mask in 0..(1u32 << i) {
+            let is_fill = |index: usize| mask & (1 << index) != 0;
+            let elements = ty.iter().enumerate().map(|(index, ty)| {
+                if is_fill(index) {
+                    quote!(Fill<#ty>)
+                } else {
+                    quote!(#ty)
+                }
+            });
+            let bounds = ty.iter().enumerate().map(|(index, ty)| {
+                if is_fill(index) {
+                    quote!(#ty: Resource + FromWorld)
+                } else {
+                    quote!(#ty: Resource)
+                }
+            });
+            let ids = ty.iter().enumerate().map(|(index, ty)| {
+                let member = Index::from(index);
+                if is_fill(index) {
+                    quote!(world.init_resource::<#ty>())
+                } else {
+                    quote!({
+                        world.insert_resource(self.#member);
+                        world.components().resource_id::<#ty>().unwrap()
+                    })
+                }
+            });
+            tokens.extend(TokenStream::from(quote! {
+                impl<#(#bounds,)*> InsertFilledResources for (#(#elements,)*) {
+                    type IDS = [ComponentId; #i];
+
+                    #[track_caller]
+                    fn insert_filled_resources(self, world: &mut World) -> Self::IDS {
+                        #debug_assert_distinct
+                        [#(#ids,)*]
+                    }
+
+                    #resource_types
+                }
+            }));
+        }
+    }
+
     tokens
 }
+
+#[proc_macro_derive(ResourceGroup, attributes(resource_group))]
+pub fn derive_resource_group(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input,
+                "`ResourceGroup` can only be derived for structs",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    if fields.is_empty() {
+        return syn::Error::new_spanned(&input, "`ResourceGroup` requires at least one field")
+            .to_compile_error()
+            .into();
+    }
+
+    let mut insert_only = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("resource_group"))
+    {
+        match attr.parse_args::<Ident>() {
+            Ok(ident) if ident == "insert_only" => insert_only = true,
+            _ => {
+                return syn::Error::new_spanned(attr, "expected `#[resource_group(insert_only)]`")
+                    .to_compile_error()
+                    .into()
+            }
+        }
+    }
+
+    let members = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| Member::Named(field.ident.clone().unwrap()))
+            .collect::<Vec<_>>(),
+        _ => (0..fields.len())
+            .map(|i| Member::Unnamed(Index::from(i)))
+            .collect(),
+    };
+    let field_tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+
+    let krate = quote!(::bevy_proto_resource_tuples);
+    let world = quote!(#krate::bevy_ecs::world::World);
+    let component_id = quote!(#krate::bevy_ecs::component::ComponentId);
+
+    let mut tokens = proc_macro2::TokenStream::new();
+
+    // Named structs get a companion struct of ids, so each id keeps the name of its resource.
+    // Tuple structs keep the array of ids from the tuple of their fields.
+    let ids_struct = match fields {
+        Fields::Named(fields) => {
+            let vis = &input.vis;
+            let ids_name = Ident::new(&format!("{name}Ids"), name.span());
+            let field_names = fields
+                .named
+                .iter()
+                .map(|field| field.ident.clone().unwrap())
+                .collect::<Vec<_>>();
+            let field_docs = field_names
+                .iter()
+                .map(|field| format!("The [`ComponentId`] of the `{field}` resource."));
+            let doc = format!("The [`ComponentId`]s of the resources in [`{name}`].");
+            let indices = 0..field_names.len();
+
+            tokens.extend(quote! {
+                #[doc = #doc]
+                ///
+                /// [`ComponentId`]: ::bevy_proto_resource_tuples::bevy_ecs::component::ComponentId
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                #vis struct #ids_name {
+                    #(
+                        #[doc = #field_docs]
+                        pub #field_names: #component_id,
+                    )*
+                }
+            });
+
+            Some((ids_name, quote!(#(#field_names: ids[#indices],)*)))
+        }
+        _ => None,
+    };
+
+    let len = field_tys.len();
+    let ids_ty = |tr: &proc_macro2::TokenStream| match &ids_struct {
+        Some((ids_name, _)) => quote!(#ids_name),
+        None => quote!(<(#(#field_tys,)*) as #tr>::IDS),
+    };
+    let ids_bound = |tr: &proc_macro2::TokenStream| match &ids_struct {
+        Some(_) => quote!(#tr<IDS = [#component_id; #len]>),
+        None => quote!(#tr),
+    };
+    let wrap_ids = |ids: proc_macro2::TokenStream| match &ids_struct {
+        Some((ids_name, fields)) => quote!({
+            let ids = #ids;
+            #ids_name { #fields }
+        }),
+        None => ids,
+    };
+
+    let insert_trait = quote!(#krate::InsertResources);
+    let (insert_ids, insert_bound) = (ids_ty(&insert_trait), ids_bound(&insert_trait));
+    let insert = wrap_ids(quote!(#insert_trait::insert_resources((#(self.#members,)*), world)));
+    let insert_dedup = wrap_ids(quote!(
+        #insert_trait::insert_resources_dedup((#(self.#members,)*), world)
+    ));
+    let insert_defer_drop = wrap_ids(quote!(
+        #insert_trait::insert_resources_defer_drop((#(self.#members,)*), world, drops)
+    ));
+    tokens.extend(quote! {
+        impl #impl_generics #krate::InsertResources for #name #ty_generics
+        where
+            (#(#field_tys,)*): #insert_bound,
+            #predicates
+        {
+            const LEN: usize = <(#(#field_tys,)*) as #krate::InsertResources>::LEN;
+
+            type IDS = #insert_ids;
+            type Inserted = <(#(#field_tys,)*) as #insert_trait>::Inserted;
+            type TypeIds = <(#(#field_tys,)*) as #insert_trait>::TypeIds;
+
+            #[track_caller]
+            fn insert_resources(self, world: &mut #world) -> Self::IDS {
+                #insert
+            }
+
+            fn insert_resources_dedup(self, world: &mut #world) -> Self::IDS {
+                #insert_dedup
+            }
+
+            #[track_caller]
+            fn insert_resources_report(self, world: &mut #world) -> Self::Inserted {
+                #insert_trait::insert_resources_report((#(self.#members,)*), world)
+            }
+
+            #[track_caller]
+            fn insert_resources_defer_drop(
+                self,
+                world: &mut #world,
+                drops: &mut #krate::DeferredDrops,
+            ) -> Self::IDS {
+                #insert_defer_drop
+            }
+
+            #[track_caller]
+            fn insert_resources_if_absent(self, world: &mut #world) -> Self::Inserted {
+                #insert_trait::insert_resources_if_absent((#(self.#members,)*), world)
+            }
+
+            fn type_ids() -> Self::TypeIds {
+                <(#(#field_tys,)*) as #insert_trait>::type_ids()
+            }
+
+            fn resource_types(types: &mut ::std::vec::Vec<(::std::any::TypeId, &'static str)>) {
+                <(#(#field_tys,)*) as #insert_trait>::resource_types(types)
+            }
+
+            fn existing_type_names(world: &#world, type_names: &mut Vec<&'static str>) {
+                <(#(#field_tys,)*) as #krate::InsertResources>::existing_type_names(world, type_names)
+            }
+        }
+    });
+
+    if !insert_only {
+        let init_trait = quote!(#krate::InitResources);
+        let (init_ids, init_bound) = (ids_ty(&init_trait), ids_bound(&init_trait));
+        let init = wrap_ids(quote!(<(#(#field_tys,)*) as #init_trait>::init_resources(world)));
+        let init_verbose =
+            wrap_ids(quote!(<(#(#field_tys,)*) as #init_trait>::init_resources_verbose(world)));
+        tokens.extend(quote! {
+            impl #impl_generics #krate::InitResources for #name #ty_generics
+            where
+                (#(#field_tys,)*): #init_bound,
+                #predicates
+            {
+                const LEN: usize = <(#(#field_tys,)*) as #krate::InitResources>::LEN;
+
+                type IDS = #init_ids;
+                type Report = <(#(#field_tys,)*) as #krate::InitResources>::Report;
+                type TypeNames = <(#(#field_tys,)*) as #krate::InitResources>::TypeNames;
+                type TypeIds = <(#(#field_tys,)*) as #krate::InitResources>::TypeIds;
+
+                #[track_caller]
+                fn init_resources(world: &mut #world) -> Self::IDS {
+                    #init
+                }
+
+                #[track_caller]
+                fn init_resources_verbose(world: &mut #world) -> Self::IDS {
+                    #init_verbose
+                }
+
+                #[track_caller]
+                fn init_resources_report(world: &mut #world) -> Self::Report {
+                    <(#(#field_tys,)*) as #krate::InitResources>::init_resources_report(world)
+                }
+
+                #[track_caller]
+                fn init_resources_map(
+                    world: &mut #world,
+                ) -> ::std::collections::HashMap<::std::any::TypeId, #component_id> {
+                    <(#(#field_tys,)*) as #init_trait>::init_resources_map(world)
+                }
+
+                fn type_names() -> Self::TypeNames {
+                    <(#(#field_tys,)*) as #init_trait>::type_names()
+                }
+
+                fn type_ids() -> Self::TypeIds {
+                    <(#(#field_tys,)*) as #init_trait>::type_ids()
+                }
+
+                fn resource_types(types: &mut ::std::vec::Vec<(::std::any::TypeId, &'static str)>) {
+                    <(#(#field_tys,)*) as #init_trait>::resource_types(types)
+                }
+
+                fn index_of<T: 'static>() -> ::std::option::Option<usize> {
+                    <(#(#field_tys,)*) as #init_trait>::index_of::<T>()
+                }
+
+                fn register_resource_ids(world: &mut #world) {
+                    <(#(#field_tys,)*) as #init_trait>::register_resource_ids(world)
+                }
+            }
+        });
+    }
+
+    tokens.into()
+}
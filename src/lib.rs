@@ -5,7 +5,7 @@
 //! ## Usage
 //! ```no_run
 //! use bevy::prelude::*;
-//! use bevy_proto_resource_tuples::*;
+//! use bevy_proto_resource_tuples::prelude::*;
 //!
 //! #[derive(Resource)]
 //! struct ResourceA;
@@ -50,6 +50,10 @@
 //!
 //! Because this prototype is a separate crate, I can't implement the traits for the base case (`P0`) due to orphan rules.
 //! So when working with a single resource, it's necessary to call `init_resource`/`insert_resource`.
+//! The empty tuple `()` is supported though, as a group that does nothing, so generic code can produce it.
+//! Tuples support up to 16 resources, and larger groups can be nested with `chain` or built at runtime with `ResourceGroupBuilder`.
+//!
+//! The crate requires `std`, since `bevy_ecs`, `bevy_app` and `bevy_log` do in Bevy 0.10.
 //!
 //! ## Patterns
 //!
@@ -61,267 +65,6582 @@
 //! let [a, b, c] = world.init_resources::<(A, B, C)>();
 //! ```
 //!
-//! ```ignore
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_proto_resource_tuples::prelude::*;
+//! #
+//! # #[derive(Resource, Default)]
+//! # struct Foo<T: Send + Sync + 'static>(T);
+//! #
+//! # #[derive(Resource, Default)]
+//! # struct Bar<T: Send + Sync + 'static>(T);
+//! #
+//! # let mut app = App::new();
 //! // It's possible to create type aliases for multiple resources.
 //! type MyResources<T> = (Foo<T>, Bar<T>);
 //!
-//! app.init_resources(MyResources<i32>);
+//! app.init_resources::<MyResources<i32>>();
+//! # assert!(app.world.contains_resource::<Bar<i32>>());
 //! ```
 
-use std::marker::PhantomData;
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
 
-use bevy_app::App;
+use bevy_app::{App, AppLabel, IntoSystemAppConfig, Plugin};
 use bevy_ecs::{
-    component::ComponentId,
-    system::{Command, Commands, Resource},
-    world::{FromWorld, World},
+    change_detection::DetectChangesMut,
+    component::{ComponentId, ComponentTicks},
+    ptr::Ptr,
+    schedule::{FreeSystemSet, IntoSystemConfig, OnEnter, ScheduleLabel, States},
+    system::{Command, Commands, IntoSystem, Res, Resource, System, SystemState},
+    world::{FromWorld, Mut, World},
 };
+use bevy_log::warn;
+
+/// Derives [`InitResources`] and [`InsertResources`] for a struct whose fields are resources,
+/// giving a named group of resources instead of a tuple.
+///
+/// Each field is initialized or inserted in declaration order, just like the elements of a tuple,
+/// so a group can have at most 16 fields.
+/// Initializing requires every field to implement [`FromWorld`];
+/// for groups that are only ever inserted, add `#[resource_group(insert_only)]` to skip [`InitResources`].
+///
+/// For structs with named fields, the derive also generates a companion struct named after the group
+/// with an `Ids` suffix, which has a [`ComponentId`] field for each resource field.
+/// It's returned in place of the array by `init_resources` and `insert_resources`.
+/// Tuple structs return an array of [`ComponentId`]s, like tuples do.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::prelude::*;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct MusicState(f32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct SfxState(f32);
+/// #
+/// #[derive(ResourceGroup)]
+/// struct AudioResources {
+///     music: MusicState,
+///     sfx: SfxState,
+/// }
+///
+/// let mut world = World::new();
+/// let ids: AudioResourcesIds = world.init_resources::<AudioResources>();
+///
+/// world.insert_resources(AudioResources {
+///     music: MusicState(0.5),
+///     sfx: SfxState(1.0),
+/// });
+/// assert_eq!(world.resource::<MusicState>().0, 0.5);
+/// assert_eq!(world.components().resource_id::<SfxState>(), Some(ids.sfx));
+/// ```
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::prelude::*;
+/// #
+/// # #[derive(Resource)]
+/// # struct Seed(u64);
+/// #
+/// # #[derive(Resource)]
+/// # struct Name<T: Send + Sync + 'static>(T);
+/// #
+/// #[derive(ResourceGroup)]
+/// #[resource_group(insert_only)]
+/// struct Config<T: Send + Sync + 'static>(Seed, Name<T>);
+///
+/// let mut world = World::new();
+/// world.insert_resources(Config(Seed(42), Name("level")));
+/// assert_eq!(world.resource::<Name<&str>>().0, "level");
+/// ```
+pub use bevy_proto_resource_tuples_macros::ResourceGroup;
+
+#[doc(hidden)]
+pub use bevy_ecs;
+
+/// The extension traits, for glob importing.
+///
+/// ```
+/// use bevy_proto_resource_tuples::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{
+        AppInitResources, AppInsertOptionalResources, AppInsertOrInitResources, AppInsertResources,
+        AppInsertResourcesOnEnter, AppOnResourcesChanged, AppResourceAudit, CommandsInitResources,
+        CommandsInsertResources, CommandsRemoveResources, ResourceGroup, WorldChainedResources,
+        WorldCloneResources, WorldContainsResources, WorldEnsureResources, WorldExtendResources,
+        WorldForEachResource, WorldForEachResourceWithWorld, WorldGetResources,
+        WorldInitDefaultResources, WorldInitResources, WorldInitResourcesAtTick,
+        WorldInitResourcesMut, WorldInitResourcesReport, WorldInitResourcesSeeded,
+        WorldInitResourcesTwoPhase, WorldInitResourcesTyped, WorldInitResourcesValidated,
+        WorldInsertBoxedResources, WorldInsertCowResources, WorldInsertErasedResources,
+        WorldInsertFilledResources, WorldInsertOptionalResources, WorldInsertOrInitResources,
+        WorldInsertResources, WorldInsertResourcesCloned, WorldInsertResourcesDeferDrop,
+        WorldInsertResourcesIfAbsent, WorldInsertResourcesReport, WorldInsertResourcesWithPolicy,
+        WorldInsertResultResources, WorldMoveResources, WorldRemoveResources, WorldResetResources,
+        WorldResourceChangeTicks, WorldResourceIds, WorldResourcePtrs, WorldResourcesScope,
+        WorldSnapshotResources, WorldSwapResources, WorldTakeResources, WorldTryInitResources,
+        WorldTryInsertResources, WorldUpdateResources, WorldWithResources,
+    };
+
+    #[cfg(feature = "asset")]
+    pub use crate::AppInitResourcesFromAsset;
+    #[cfg(feature = "timing")]
+    pub use crate::WorldInitResourcesTimed;
+    #[cfg(feature = "reflect")]
+    pub use crate::{AppRegisterResources, WorldReflectResources};
+}
+
+/// Resources that can be initialized in the [`World`] together.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::InitResources;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct A;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct B;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct C;
+/// #
+/// assert_eq!(<(A, B, C) as InitResources>::LEN, 3);
+/// ```
+pub trait InitResources: Send + Sync + 'static {
+    /// The number of resources.
+    const LEN: usize;
+
+    type IDS;
+    type Report;
+    type TypeNames;
+    type TypeIds;
+
+    fn init_resources(world: &mut World) -> Self::IDS;
+
+    fn init_resources_verbose(world: &mut World) -> Self::IDS;
+
+    fn init_resources_report(world: &mut World) -> Self::Report;
+
+    fn init_resources_map(world: &mut World) -> HashMap<TypeId, ComponentId>;
+
+    /// Returns the type names of the resources, in the same order as the [`ComponentId`]s from `init_resources`.
+    ///
+    /// The names come from [`std::any::type_name`], so they're only meant for diagnostics:
+    /// their exact format isn't guaranteed and may change between compiler versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, InitResources};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let ids = world.init_resources::<(Score, Lives)>();
+    ///
+    /// for (name, id) in <(Score, Lives)>::type_names().into_iter().zip(ids) {
+    ///     println!("initialized {name} as {id:?}");
+    /// }
+    /// ```
+    fn type_names() -> Self::TypeNames;
+
+    /// Returns the [`TypeId`]s of the resources, in the same order as the [`ComponentId`]s from `init_resources`.
+    ///
+    /// Unlike the [`ComponentId`]s, these don't depend on a [`World`],
+    /// so they can describe a group without initializing anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::InitResources;
+    /// # use std::any::TypeId;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let ids = <(Score, Lives) as InitResources>::type_ids();
+    /// assert_eq!(ids, [TypeId::of::<Score>(), TypeId::of::<Lives>()]);
+    /// ```
+    fn type_ids() -> Self::TypeIds;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    ///
+    /// Unlike `type_ids` and `type_names`, this works the same for every group, including [`Chain`]s.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+
+    /// Returns the position of the resource `T` in the group, if it's part of it,
+    /// for indexing the [`ComponentId`]s from `init_resources` by type.
+    ///
+    /// If `T` appears more than once, the first position is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, InitResources};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Level(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let ids = world.init_resources::<(Score, Lives)>();
+    ///
+    /// let lives = <(Score, Lives)>::index_of::<Lives>().unwrap();
+    /// assert_eq!(world.components().resource_id::<Lives>(), Some(ids[lives]));
+    /// assert_eq!(<(Score, Lives)>::index_of::<Level>(), None);
+    /// ```
+    fn index_of<T: 'static>() -> Option<usize>;
+
+    /// Registers the [`ComponentId`]s of the resources without initializing them.
+    fn register_resource_ids(world: &mut World);
+}
+
+/// Initializes the resource `R` for each element of the array.
+///
+/// Since every element shares the same type, they all refer to the same resource:
+/// it is only created once and every returned [`ComponentId`] is identical.
+/// Arrays don't create `N` separate resources.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::prelude::*;
+/// #
+/// #[derive(Resource, Default)]
+/// struct Counter(usize);
+///
+/// let mut world = World::new();
+/// let [id] = world.init_resources::<[Counter; 1]>();
+/// assert_eq!(id, world.init_resource::<Counter>());
+///
+/// let [a, b] = world.init_resources::<[Counter; 2]>();
+/// assert_eq!(a, b);
+/// ```
+impl<R: Resource + FromWorld, const N: usize> InitResources for [R; N] {
+    const LEN: usize = N;
+
+    type IDS = [ComponentId; N];
+    type Report = [bool; N];
+    type TypeNames = [&'static str; N];
+    type TypeIds = [TypeId; N];
+
+    #[track_caller]
+    fn init_resources(world: &mut World) -> Self::IDS {
+        std::array::from_fn(|_| init_resource_checked::<R>(world))
+    }
+
+    #[track_caller]
+    fn init_resources_verbose(world: &mut World) -> Self::IDS {
+        std::array::from_fn(|_| {
+            warn_if_present::<R>(world);
+            init_resource_checked::<R>(world)
+        })
+    }
+
+    #[track_caller]
+    fn init_resources_report(world: &mut World) -> Self::Report {
+        std::array::from_fn(|_| {
+            let created = !world.contains_resource::<R>();
+            init_resource_checked::<R>(world);
+            created
+        })
+    }
+
+    #[track_caller]
+    fn init_resources_map(world: &mut World) -> HashMap<TypeId, ComponentId> {
+        if N == 0 {
+            return HashMap::new();
+        }
+        HashMap::from([(TypeId::of::<R>(), init_resource_checked::<R>(world))])
+    }
+
+    fn type_names() -> Self::TypeNames {
+        [std::any::type_name::<R>(); N]
+    }
+
+    fn type_ids() -> Self::TypeIds {
+        [TypeId::of::<R>(); N]
+    }
+
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>) {
+        types.extend([(TypeId::of::<R>(), std::any::type_name::<R>()); N]);
+    }
+
+    fn index_of<T: 'static>() -> Option<usize> {
+        (N > 0 && TypeId::of::<T>() == TypeId::of::<R>()).then_some(0)
+    }
+
+    fn register_resource_ids(world: &mut World) {
+        if N > 0 {
+            register_resource_id::<R>(world);
+        }
+    }
+}
+
+/// The empty group, so generic code that ends up with no resources still compiles.
+///
+/// Initializing it does nothing and returns an empty array.
+impl InitResources for () {
+    const LEN: usize = 0;
+
+    type IDS = [ComponentId; 0];
+    type Report = [bool; 0];
+    type TypeNames = [&'static str; 0];
+    type TypeIds = [TypeId; 0];
+
+    fn init_resources(_world: &mut World) -> Self::IDS {
+        []
+    }
+
+    fn init_resources_verbose(_world: &mut World) -> Self::IDS {
+        []
+    }
+
+    fn init_resources_report(_world: &mut World) -> Self::Report {
+        []
+    }
+
+    fn init_resources_map(_world: &mut World) -> HashMap<TypeId, ComponentId> {
+        HashMap::new()
+    }
+
+    fn type_names() -> Self::TypeNames {
+        []
+    }
+
+    fn type_ids() -> Self::TypeIds {
+        []
+    }
+
+    fn resource_types(_types: &mut Vec<(TypeId, &'static str)>) {}
+
+    fn index_of<T: 'static>() -> Option<usize> {
+        None
+    }
+
+    fn register_resource_ids(_world: &mut World) {}
+}
+
+/// The empty group, so generic code that ends up with no resources still compiles.
+///
+/// Inserting it does nothing and returns an empty array.
+impl InsertResources for () {
+    const LEN: usize = 0;
+
+    type IDS = [ComponentId; 0];
+    type Inserted = [bool; 0];
+    type TypeIds = [TypeId; 0];
+
+    fn insert_resources(self, _world: &mut World) -> Self::IDS {
+        []
+    }
+
+    fn insert_resources_if_absent(self, _world: &mut World) -> Self::Inserted {
+        []
+    }
+
+    fn insert_resources_dedup(self, _world: &mut World) -> Self::IDS {
+        []
+    }
+
+    fn insert_resources_report(self, _world: &mut World) -> Self::Inserted {
+        []
+    }
+
+    fn insert_resources_defer_drop(
+        self,
+        _world: &mut World,
+        _drops: &mut DeferredDrops,
+    ) -> Self::IDS {
+        []
+    }
+
+    fn type_ids() -> Self::TypeIds {
+        []
+    }
+
+    fn resource_types(_types: &mut Vec<(TypeId, &'static str)>) {}
+
+    fn existing_type_names(_world: &World, _type_names: &mut Vec<&'static str>) {}
+}
+
+/// Logs a warning if `R` already exists, since initializing it would be skipped.
+fn warn_if_present<R: Resource>(world: &World) {
+    if world.contains_resource::<R>() {
+        warn!(
+            "resource `{}` already exists, so it wasn't initialized",
+            std::any::type_name::<R>()
+        );
+    }
+}
+
+/// Drops a resource value that was skipped or is being overwritten.
+///
+/// With the `catch_drop_panics` feature, a panic in its [`Drop`] implementation is caught and logged,
+/// so it can't turn into a second panic while unwinding, which would abort the process.
+fn drop_resource<R: Resource>(resource: R) {
+    #[cfg(feature = "catch_drop_panics")]
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(resource))).is_err() {
+        bevy_log::error!(
+            "dropping resource `{}` panicked",
+            std::any::type_name::<R>()
+        );
+    }
+    #[cfg(not(feature = "catch_drop_panics"))]
+    drop(resource);
+}
+
+/// Inserts the resource, overwriting any existing value.
+///
+/// With the `catch_drop_panics` feature, the existing value is removed first and dropped with [`drop_resource`],
+/// so the resource is reinserted and counts as added afterwards.
+fn replace_resource<R: Resource>(world: &mut World, resource: R) {
+    #[cfg(feature = "catch_drop_panics")]
+    if let Some(previous) = world.remove_resource::<R>() {
+        drop_resource(previous);
+    }
+    world.insert_resource(resource);
+}
+
+/// A fallible version of [`FromWorld`], for resources that can fail to initialize.
+pub trait TryFromWorld: Sized {
+    type Error;
+
+    fn try_from_world(world: &mut World) -> Result<Self, Self::Error>;
+}
+
+/// Resources that can be initialized in the [`World`] together with [`TryFromWorld`].
+///
+/// Every resource in the tuple must have the same [`TryFromWorld::Error`] type.
+pub trait TryInitResources: Send + Sync + 'static {
+    type IDS;
+    type Error;
+
+    fn try_init_resources(world: &mut World) -> Result<Self::IDS, Self::Error>;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Resources that can be initialized in the [`World`] together with their [`Default`] values.
+pub trait DefaultResources: InitResources {}
+
+/// Resources that can be initialized in the [`World`] together and then borrowed mutably.
+pub trait InitResourcesMut: InitResources {
+    type Muts<'w>;
+
+    fn init_resources_mut(world: &mut World) -> Self::Muts<'_>;
+}
+
+/// Panics if the same resource appears more than once in `ids`.
+#[track_caller]
+fn assert_distinct_resources<T: PartialEq>(ids: &[T], type_names: &[&str]) {
+    for (i, id) in ids.iter().enumerate() {
+        if ids[..i].contains(id) {
+            panic!(
+                "resource `{}` appears more than once in the tuple",
+                type_names[i]
+            );
+        }
+    }
+}
+
+/// Logs a warning for each position in `ids` that repeats an earlier resource.
+#[cfg(debug_assertions)]
+fn warn_duplicate_resources<T: PartialEq>(ids: &[T], type_names: &[&str]) {
+    for (i, id) in ids.iter().enumerate() {
+        if let Some(first) = ids[..i].iter().position(|other| other == id) {
+            bevy_log::warn!(
+                "resource `{}` at position {} overwrites the one at position {}",
+                type_names[i],
+                i,
+                first
+            );
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    /// The resources whose [`FromWorld`] implementations are currently running on this thread.
+    static INITIALIZING_RESOURCES: std::cell::RefCell<Vec<std::any::TypeId>> = Default::default();
+}
+
+/// Removes the resource from [`INITIALIZING_RESOURCES`] when dropped, even while unwinding.
+#[cfg(debug_assertions)]
+struct InitializingResourceGuard;
+
+#[cfg(debug_assertions)]
+impl Drop for InitializingResourceGuard {
+    fn drop(&mut self) {
+        INITIALIZING_RESOURCES.with(|ids| ids.borrow_mut().pop());
+    }
+}
+
+/// Initializes the resource, panicking in debug builds if its [`FromWorld`] implementation
+/// ends up initializing the same resource again.
+#[track_caller]
+fn init_resource_checked<R: Resource + FromWorld>(world: &mut World) -> ComponentId {
+    #[cfg(debug_assertions)]
+    let _guard = {
+        let id = std::any::TypeId::of::<R>();
+        INITIALIZING_RESOURCES.with(|ids| {
+            let mut ids = ids.borrow_mut();
+            if ids.contains(&id) {
+                panic!(
+                    "resource `{}` is initialized again while its `FromWorld` implementation is running; \
+                     initialize the resources it depends on earlier in the tuple instead",
+                    std::any::type_name::<R>()
+                );
+            }
+            ids.push(id);
+        });
+        InitializingResourceGuard
+    };
+    world.init_resource::<R>()
+}
+
+/// Registers the [`ComponentId`] of `R` without inserting a value.
+///
+/// Bevy 0.10 doesn't expose this directly,
+/// but initializing a system parameter that reads the resource registers it the same way.
+fn register_resource_id<R: Resource>(world: &mut World) -> ComponentId {
+    SystemState::<Option<Res<R>>>::new(world);
+    world.components().resource_id::<R>().unwrap()
+}
+
+/// Resources that can be inserted into the [`World`] together.
+///
+/// Factory functions can return `impl InsertResources` to hide the exact tuple,
+/// and their results can be combined with [`chain`](ChainResources::chain), since tuples can't be nested.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::{prelude::*, InsertResources};
+/// #
+/// # #[derive(Resource)]
+/// # struct Volume(f32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Muted(bool);
+/// #
+/// fn audio() -> impl InsertResources {
+///     (Volume(0.5), Muted(false))
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resources(audio());
+/// assert_eq!(world.resource::<Volume>().0, 0.5);
+/// ```
+pub trait InsertResources: Send + Sync + 'static {
+    /// The number of resources.
+    const LEN: usize;
+
+    type IDS;
+    type Inserted;
+    type TypeIds;
+
+    fn insert_resources(self, world: &mut World) -> Self::IDS;
+
+    fn insert_resources_if_absent(self, world: &mut World) -> Self::Inserted;
+
+    /// Inserts the resources like `insert_resources`, but logs duplicates instead of panicking on them.
+    fn insert_resources_dedup(self, world: &mut World) -> Self::IDS;
+
+    /// Inserts the resources like `insert_resources`, reporting which ones overwrote an existing value.
+    fn insert_resources_report(self, world: &mut World) -> Self::Inserted;
+
+    /// Inserts the resources like `insert_resources`, moving the values they overwrite into `drops`
+    /// instead of dropping them.
+    fn insert_resources_defer_drop(self, world: &mut World, drops: &mut DeferredDrops)
+        -> Self::IDS;
+
+    /// Returns the [`TypeId`]s of the resources, in the same order as the [`ComponentId`]s from `insert_resources`.
+    fn type_ids() -> Self::TypeIds;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+
+    /// Appends the type names of the resources that already exist in the [`World`] to `type_names`.
+    fn existing_type_names(world: &World, type_names: &mut Vec<&'static str>);
+
+    fn try_insert_resources(self, world: &mut World) -> Result<Self::IDS, InsertError>
+    where
+        Self: Sized,
+    {
+        let mut type_names = Vec::new();
+        Self::existing_type_names(world, &mut type_names);
+
+        if !type_names.is_empty() {
+            return Err(InsertError { type_names });
+        }
+
+        Ok(self.insert_resources(world))
+    }
+}
+
+/// Two groups of resources chained together, so they can be initialized or inserted as one group.
+///
+/// This allows composing groups, since tuples of tuples aren't groups themselves.
+/// The first group is always initialized or inserted before the second.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::{prelude::*, Chain, ChainResources};
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Music;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Sfx;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Gravity;
+/// #
+/// type AudioResources = (Music, Sfx);
+/// type PhysicsResources = (Gravity,);
+///
+/// let mut world = World::new();
+/// let ([music, sfx], [gravity]) = world.init_resources::<Chain<AudioResources, PhysicsResources>>();
+///
+/// world.insert_resources((Music, Sfx).chain((Gravity,)));
+/// ```
+pub struct Chain<A, B>(pub A, pub B);
+
+impl<A: InitResources, B: InitResources> InitResources for Chain<A, B> {
+    const LEN: usize = A::LEN + B::LEN;
+
+    type IDS = (A::IDS, B::IDS);
+    type Report = (A::Report, B::Report);
+    type TypeNames = (A::TypeNames, B::TypeNames);
+    type TypeIds = (A::TypeIds, B::TypeIds);
+
+    #[track_caller]
+    fn init_resources(world: &mut World) -> Self::IDS {
+        (A::init_resources(world), B::init_resources(world))
+    }
+
+    #[track_caller]
+    fn init_resources_verbose(world: &mut World) -> Self::IDS {
+        (
+            A::init_resources_verbose(world),
+            B::init_resources_verbose(world),
+        )
+    }
+
+    #[track_caller]
+    fn init_resources_report(world: &mut World) -> Self::Report {
+        (
+            A::init_resources_report(world),
+            B::init_resources_report(world),
+        )
+    }
+
+    #[track_caller]
+    fn init_resources_map(world: &mut World) -> HashMap<TypeId, ComponentId> {
+        let mut ids = A::init_resources_map(world);
+        ids.extend(B::init_resources_map(world));
+        ids
+    }
+
+    fn type_names() -> Self::TypeNames {
+        (A::type_names(), B::type_names())
+    }
+
+    fn type_ids() -> Self::TypeIds {
+        (A::type_ids(), B::type_ids())
+    }
+
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>) {
+        A::resource_types(types);
+        B::resource_types(types);
+    }
+
+    /// Returns the position of `T` counting across both groups, as if they were one flat tuple.
+    fn index_of<T: 'static>() -> Option<usize> {
+        A::index_of::<T>().or_else(|| B::index_of::<T>().map(|i| A::LEN + i))
+    }
+
+    fn register_resource_ids(world: &mut World) {
+        A::register_resource_ids(world);
+        B::register_resource_ids(world);
+    }
+}
+
+impl<A: InsertResources, B: InsertResources> InsertResources for Chain<A, B> {
+    const LEN: usize = A::LEN + B::LEN;
+
+    type IDS = (A::IDS, B::IDS);
+    type Inserted = (A::Inserted, B::Inserted);
+    type TypeIds = (A::TypeIds, B::TypeIds);
+
+    #[track_caller]
+    fn insert_resources(self, world: &mut World) -> Self::IDS {
+        (
+            self.0.insert_resources(world),
+            self.1.insert_resources(world),
+        )
+    }
+
+    fn insert_resources_dedup(self, world: &mut World) -> Self::IDS {
+        (
+            self.0.insert_resources_dedup(world),
+            self.1.insert_resources_dedup(world),
+        )
+    }
+
+    #[track_caller]
+    fn insert_resources_report(self, world: &mut World) -> Self::Inserted {
+        (
+            self.0.insert_resources_report(world),
+            self.1.insert_resources_report(world),
+        )
+    }
+
+    #[track_caller]
+    fn insert_resources_defer_drop(
+        self,
+        world: &mut World,
+        drops: &mut DeferredDrops,
+    ) -> Self::IDS {
+        (
+            self.0.insert_resources_defer_drop(world, drops),
+            self.1.insert_resources_defer_drop(world, drops),
+        )
+    }
+
+    #[track_caller]
+    fn insert_resources_if_absent(self, world: &mut World) -> Self::Inserted {
+        (
+            self.0.insert_resources_if_absent(world),
+            self.1.insert_resources_if_absent(world),
+        )
+    }
+
+    fn type_ids() -> Self::TypeIds {
+        (A::type_ids(), B::type_ids())
+    }
+
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>) {
+        A::resource_types(types);
+        B::resource_types(types);
+    }
+
+    fn existing_type_names(world: &World, type_names: &mut Vec<&'static str>) {
+        A::existing_type_names(world, type_names);
+        B::existing_type_names(world, type_names);
+    }
+}
+
+/// Fallibly constructed resources that can be inserted into the [`World`] together,
+/// as long as none of them failed.
+///
+/// Like [`InsertCowResources`], this isn't `'static`, so the error type can borrow.
+pub trait InsertResultResources<E> {
+    type IDS;
+
+    fn insert_result_resources(self, world: &mut World) -> Result<Self::IDS, E>;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Extends groups of resources with `chain`.
+pub trait ChainResources: Sized {
+    /// Chains another group of resources after this one, so they can be inserted together.
+    fn chain<B>(self, other: B) -> Chain<Self, B> {
+        Chain(self, other)
+    }
+}
+
+impl<A: InsertResources> ChainResources for A {}
+
+/// Optional resources that can be inserted into the [`World`] together, skipping the ones that are `None`.
+pub trait InsertOptionalResources: Send + Sync + 'static {
+    type IDS;
+
+    fn insert_optional_resources(self, world: &mut World) -> Self::IDS;
+
+    /// Appends the [`TypeId`] and type name of each resource that has a value to `types`, in order.
+    fn resource_types(&self, types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Boxed resources that can be unboxed and inserted into the [`World`] together.
+pub trait InsertBoxedResources: Send + Sync + 'static {
+    type IDS;
+
+    fn insert_boxed_resources(self, world: &mut World) -> Self::IDS;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Resources that are either borrowed or owned, and can be inserted into the [`World`] together.
+///
+/// Borrowed resources are cloned and owned ones are moved, so unlike the other traits,
+/// this isn't `'static`.
+pub trait InsertCowResources {
+    type IDS;
+
+    fn insert_cow_resources(self, world: &mut World) -> Self::IDS;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// A type-erased resource that knows how to insert itself into the [`World`].
+///
+/// This is implemented for every [`Resource`], so any resource can be boxed as a `Box<dyn ErasedResource>`
+/// and inserted later without knowing its type.
+pub trait ErasedResource: Send + Sync + 'static {
+    fn insert_into(self: Box<Self>, world: &mut World);
+}
+
+impl<R: Resource> ErasedResource for R {
+    fn insert_into(self: Box<Self>, world: &mut World) {
+        world.insert_resources((*self,));
+    }
+}
+
+type ResourceOp = Box<dyn FnOnce(&mut World) -> ComponentId + Send + Sync>;
+
+/// A group of resources built at runtime, for when the resources aren't known at compile time.
+///
+/// Operations are applied in the order they were added, just like the elements of a tuple.
+/// There's no limit on the number of resources, so this also covers groups larger than the 16 elements tuples support,
+/// at the cost of boxing each operation and returning the ids in a [`Vec`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::ResourceGroupBuilder;
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// #[derive(Resource)]
+/// struct Seed(u64);
+///
+/// let mut world = World::new();
+/// let ids = ResourceGroupBuilder::new()
+///     .init::<Score>()
+///     .insert(Seed(42))
+///     .apply(&mut world);
+///
+/// assert_eq!(ids.len(), 2);
+/// assert_eq!(world.resource::<Seed>().0, 42);
+/// ```
+#[derive(Default)]
+pub struct ResourceGroupBuilder {
+    ops: Vec<ResourceOp>,
+}
+
+impl ResourceGroupBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a resource to initialize with [`FromWorld`], if it doesn't exist yet.
+    pub fn init<R: Resource + FromWorld>(mut self) -> Self {
+        self.ops.push(Box::new(|world| {
+            let [id] = world.init_resources::<(R,)>();
+            id
+        }));
+        self
+    }
+
+    /// Adds a resource to insert, overwriting any existing value.
+    pub fn insert<R: Resource>(mut self, resource: R) -> Self {
+        self.ops.push(Box::new(move |world| {
+            let [id] = world.insert_resources((resource,));
+            id
+        }));
+        self
+    }
+
+    /// Returns the number of operations in the builder.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the builder has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Applies the operations in order, returning the ids of the resources.
+    pub fn apply(self, world: &mut World) -> Vec<ComponentId> {
+        self.ops.into_iter().map(|op| op(world)).collect()
+    }
+}
+
+impl fmt::Debug for ResourceGroupBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceGroupBuilder")
+            .field("len", &self.ops.len())
+            .finish()
+    }
+}
+
+/// What `insert_resources_with_policy` does with resources that already exist.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// Overwrite the existing value, like `insert_resources`.
+    #[default]
+    Overwrite,
+    /// Keep the existing value, like `insert_resources_if_absent`.
+    Skip,
+    /// Insert nothing and return an error, like `try_insert_resources`.
+    Error,
+}
+
+/// What happened to a single resource in `insert_resources_with_policy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertOutcome<R> {
+    /// The resource didn't exist, and was inserted.
+    Inserted,
+    /// The resource existed, and was overwritten. Holds the previous value.
+    Overwritten(R),
+    /// The resource existed, and was kept. Holds the value that wasn't inserted.
+    Skipped(R),
+}
+
+/// Inserts a single resource according to `policy`, which must not be [`OverwritePolicy::Error`] by now.
+fn insert_with_policy<R: Resource>(
+    world: &mut World,
+    resource: R,
+    policy: OverwritePolicy,
+) -> InsertOutcome<R> {
+    match world.get_resource_mut::<R>() {
+        None => {
+            world.insert_resource(resource);
+            InsertOutcome::Inserted
+        }
+        Some(_) if policy == OverwritePolicy::Skip => InsertOutcome::Skipped(resource),
+        // Replacing the value in place only changes the resource, like `insert_resource` does.
+        Some(mut existing) => {
+            InsertOutcome::Overwritten(std::mem::replace(&mut *existing, resource))
+        }
+    }
+}
+
+/// Values overwritten by `insert_resources_defer_drop`, kept alive so they can be dropped later.
+///
+/// Dropping the handle drops the values, in the order they were overwritten.
+/// Since every resource is `Send`, the handle can be sent to another thread to drop them there.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::prelude::*;
+/// #
+/// # #[derive(Resource)]
+/// # struct Buffer(Vec<u8>);
+/// #
+/// let mut world = World::new();
+/// world.insert_resources((Buffer(vec![0; 1024]),));
+///
+/// let drops = world.insert_resources_defer_drop((Buffer(vec![1; 1024]),));
+/// assert_eq!(drops.len(), 1);
+///
+/// // Free the old buffer off the main thread.
+/// std::thread::spawn(move || drop(drops)).join().unwrap();
+/// ```
+#[derive(Default)]
+pub struct DeferredDrops(Vec<Box<dyn std::any::Any + Send + Sync>>);
+
+impl DeferredDrops {
+    /// Returns the number of values waiting to be dropped.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no values were overwritten.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Moves the values from `other` into this handle, so they're dropped together.
+    pub fn append(&mut self, other: &mut DeferredDrops) {
+        self.0.append(&mut other.0);
+    }
+}
+
+impl fmt::Debug for DeferredDrops {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeferredDrops")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+fn insert_deferring_drop<R: Resource>(
+    world: &mut World,
+    resource: R,
+    drops: &mut DeferredDrops,
+) -> ComponentId {
+    match world.get_resource_mut::<R>() {
+        // Replacing the value in place only changes the resource, like `insert_resource` does.
+        Some(mut existing) => drops
+            .0
+            .push(Box::new(std::mem::replace(&mut *existing, resource))),
+        None => world.insert_resource(resource),
+    }
+    world.components().resource_id::<R>().unwrap()
+}
+
+/// Resources that can be inserted into the [`World`] together with an [`OverwritePolicy`].
+pub trait InsertResourcesWithPolicy: InsertResources {
+    type Outcomes;
+
+    fn insert_resources_with_policy(
+        self,
+        world: &mut World,
+        policy: OverwritePolicy,
+    ) -> Result<Self::Outcomes, InsertError>;
+}
+
+/// Either a value to insert as a resource, or a marker to initialize the resource with [`FromWorld`].
+pub enum OrDefault<R> {
+    /// Insert this value, overwriting any existing resource.
+    Value(R),
+    /// Initialize the resource with [`FromWorld`], if it doesn't exist yet.
+    Default,
+}
+
+/// Resources that can be inserted or initialized in the [`World`] together.
+pub trait InsertOrInitResources: Send + Sync + 'static {
+    type IDS;
+
+    fn insert_or_init_resources(self, world: &mut World) -> Self::IDS;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// A marker to initialize the resource with [`FromWorld`] when inserted alongside values.
+///
+/// This is the type-level version of [`OrDefault::Default`]:
+/// whether each element is a value or a marker is known at compile time, so there's nothing to match on.
+/// Because every combination needs its own implementation, it's only supported for tuples of up to 4 elements.
+pub struct Fill<R>(PhantomData<R>);
+
+impl<R> Fill<R> {
+    /// Creates a marker for the resource `R`.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R> Default for Fill<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> fmt::Debug for Fill<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fill<{}>", std::any::type_name::<R>())
+    }
+}
+
+/// Resources that can be inserted or initialized in the [`World`] together,
+/// using [`Fill`] markers for the ones to initialize.
+pub trait InsertFilledResources: Send + Sync + 'static {
+    type IDS;
+
+    fn insert_filled_resources(self, world: &mut World) -> Self::IDS;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Resources that can be removed from the [`World`] together.
+pub trait RemoveResources: Sized + Send + Sync + 'static {
+    type Partial;
+    type Removed;
+
+    fn remove_resources(world: &mut World);
+
+    fn remove_resources_bool(world: &mut World) -> Self::Removed;
+
+    /// Removes the resources in reverse order, dropping each one before removing the next.
+    fn remove_resources_in_order(world: &mut World);
+
+    fn take_resources(world: &mut World) -> Option<Self>;
+
+    fn try_take_resources(world: &mut World) -> Self::Partial;
+}
+
+/// Resources that can be borrowed from the [`World`] together.
+pub trait GetResources: Send + Sync + 'static {
+    type Refs<'w>;
+
+    fn get_resources(world: &World) -> Option<Self::Refs<'_>>;
+}
+
+/// Resources that can be reset to their [`Default`] values in the [`World`] together.
+pub trait ResetResources: Send + Sync + 'static {
+    type Reset: AsRef<[bool]>;
+
+    fn reset_resources(world: &mut World) -> Self::Reset;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Resources that can have raw pointers to their storage read together.
+pub trait ResourcePtrs: Send + Sync + 'static {
+    type Ptrs<'w>;
+
+    fn resource_ptrs(world: &World) -> Self::Ptrs<'_>;
+}
+
+/// Resources that can be cloned from one [`World`] into another together.
+pub trait CloneResources: Send + Sync + 'static {
+    fn clone_resources_into(world: &World, target: &mut World);
+
+    /// Clones the resources like `clone_resources_into`, but skips the ones that already exist in `target`.
+    fn clone_resources_into_if_absent(world: &World, target: &mut World);
+
+    /// Clones the resources out of the [`World`], if all of them exist.
+    fn snapshot_resources(world: &World) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Resources that can each be transformed by their own closure from `F`, a tuple of closures.
+pub trait MapResources<F>: Send + Sync + 'static {
+    type Mapped: AsRef<[bool]>;
+
+    fn map_resources(world: &mut World, f: F) -> Self::Mapped;
+}
+
+/// A visitor that can be called with a resource of type `R`, for `for_each_resource`.
+///
+/// Implementing this generically over every `R` that implements some trait
+/// lets a whole group be visited through that trait, with a direct call for each resource.
+pub trait VisitResource<R> {
+    fn visit(&mut self, resource: &R);
+}
+
+/// A visitor that can be called with a mutable resource of type `R`, for `for_each_resource_mut`.
+pub trait VisitResourceMut<R: Resource> {
+    fn visit_mut(&mut self, resource: Mut<R>);
+}
+
+/// Resources that can be visited in the [`World`] together by `V`.
+pub trait VisitResources<V>: Send + Sync + 'static {
+    fn visit_resources(world: &World, visitor: &mut V);
+}
+
+/// Resources that can be visited mutably in the [`World`] together by `V`.
+pub trait VisitResourcesMut<V>: Send + Sync + 'static {
+    fn visit_resources_mut(world: &mut World, visitor: &mut V);
+}
+
+/// Resources that can be checked for in the [`World`] together.
+pub trait ContainsResources: Send + Sync + 'static {
+    type Contains: AsRef<[bool]>;
+
+    fn contains_resources(world: &World) -> Self::Contains;
+}
+
+/// Resources that can have their change ticks read together.
+pub trait ResourceChangeTicks: Send + Sync + 'static {
+    type Ticks;
+    type Changed: AsRef<[bool]>;
+
+    fn resource_change_ticks(world: &World) -> Self::Ticks;
+
+    fn resources_changed(world: &World, last_change_tick: u32, change_tick: u32) -> Self::Changed;
+
+    fn resources_added_since(world: &World, since: u32, change_tick: u32) -> Self::Changed;
+}
+
+/// Resources that can have their [`ComponentId`]s looked up together.
+pub trait ResourceIds: Send + Sync + 'static {
+    type IDS;
+    type IdSlots: AsRef<[Option<ComponentId>]>;
+
+    fn resource_ids(world: &World) -> Self::IDS;
+
+    /// Returns the same ids as `resource_ids`, as an array that can be iterated over.
+    fn resource_id_slots(world: &World) -> Self::IdSlots;
+}
+
+/// A [`ComponentId`] tagged with the type of the resource it belongs to.
+///
+/// Returned by `init_resources_typed`, so ids for different resources can't be mixed up.
+pub struct ResourceId<R: Resource> {
+    id: ComponentId,
+    _phantom: PhantomData<fn() -> R>,
+}
+
+impl<R: Resource> ResourceId<R> {
+    fn new(id: ComponentId) -> Self {
+        Self {
+            id,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the untyped [`ComponentId`].
+    pub fn id(self) -> ComponentId {
+        self.id
+    }
+}
+
+impl<R: Resource> Clone for ResourceId<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Resource> Copy for ResourceId<R> {}
+
+impl<R: Resource> PartialEq for ResourceId<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<R: Resource> Eq for ResourceId<R> {}
+
+impl<R: Resource> std::hash::Hash for ResourceId<R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<R: Resource> fmt::Debug for ResourceId<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(&format!("ResourceId<{}>", std::any::type_name::<R>()))
+            .field(&self.id)
+            .finish()
+    }
+}
+
+impl<R: Resource> From<ResourceId<R>> for ComponentId {
+    fn from(id: ResourceId<R>) -> Self {
+        id.id
+    }
+}
+
+/// Resources that can be initialized in the [`World`] together, returning typed ids.
+pub trait InitResourcesTyped: Send + Sync + 'static {
+    type TypedIds;
+
+    fn init_resources_typed(world: &mut World) -> Self::TypedIds;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
+}
+
+/// Extends [`World`] with `init_resources`.
+pub trait WorldInitResources {
+    /// Initializes new resources and returns a vector of the [`ComponentId`]s created for them.
+    ///
+    /// If a resource already exists, nothing happens.
+    ///
+    /// The value given by the [`FromWorld::from_world`] method will be used.
+    /// Note that any resource with the [`Default`] trait automatically implements [`FromWorld`],
+    /// and those default values will be here instead.
+    ///
+    /// Resources are guaranteed to be initialized in order from left to right,
+    /// so the [`FromWorld`] implementation of a resource can rely on the resources before it in the tuple.
+    ///
+    /// [`FromWorld`] implementations can call `init_resources` and `insert_resources` themselves,
+    /// since the [`World`] isn't borrowed while they run.
+    /// This lets a resource make sure the group of resources it depends on exists:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Wind(f32);
+    /// #
+    /// #[derive(Resource)]
+    /// struct Physics {
+    ///     gravity: f32,
+    /// }
+    ///
+    /// impl FromWorld for Physics {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         world.init_resources::<(Gravity, Wind)>();
+    ///         Physics {
+    ///             gravity: world.resource::<Gravity>().0,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resources::<(Physics,)>();
+    /// assert!(world.contains_resource::<Wind>());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the same resource type appears more than once in the tuple.
+    /// Release builds skip this check.
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.init_resources::<(Score, Score)>();
+    /// ```
+    ///
+    /// The panic is reported at the location of the `init_resources` call rather than inside this crate:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use std::{panic, sync::{Arc, Mutex}};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// let location = Arc::new(Mutex::new(None));
+    /// let hook_location = location.clone();
+    /// panic::set_hook(Box::new(move |info| {
+    ///     let panic_location = info.location().unwrap();
+    ///     *hook_location.lock().unwrap() = Some((panic_location.file().to_owned(), panic_location.line()));
+    /// }));
+    ///
+    /// let mut world = World::new();
+    /// let line = line!() + 1;
+    /// let result = panic::catch_unwind(panic::AssertUnwindSafe(|| world.init_resources::<(Score, Score)>()));
+    /// # let _ = panic::take_hook();
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(*location.lock().unwrap(), Some((file!().to_owned(), line)));
+    /// ```
+    ///
+    /// In debug builds, also panics if the [`FromWorld`] implementation of a resource
+    /// initializes the same resource again through `init_resources`, rather than recursing indefinitely.
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// struct Settings;
+    ///
+    /// impl Resource for Settings {}
+    ///
+    /// impl FromWorld for Settings {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         world.init_resources::<(Settings,)>();
+    ///         Settings
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resources::<(Settings,)>();
+    /// ```
+    fn init_resources<R: InitResources>(&mut self) -> R::IDS;
+
+    /// Initializes new resources like `init_resources`,
+    /// but logs a warning for every resource that already exists and is therefore skipped.
+    fn init_resources_verbose<R: InitResources>(&mut self) -> R::IDS;
+
+    /// Initializes new resources like `init_resources`,
+    /// but returns the [`ComponentId`]s in a map keyed by the [`TypeId`] of each resource
+    /// instead of by position.
+    ///
+    /// If the same resource type appears more than once, it only has one entry in the map.
+    /// Debug builds panic on duplicates like `init_resources` does, but release builds collapse them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let ids = world.init_resources_map::<(Score, Lives)>();
+    ///
+    /// assert_eq!(ids.len(), 2);
+    /// assert_eq!(world.components().resource_id::<Lives>(), Some(ids[&TypeId::of::<Lives>()]));
+    /// ```
+    fn init_resources_map<R: InitResources>(&mut self) -> HashMap<TypeId, ComponentId>;
+
+    /// Initializes new resources and collects the [`ComponentId`]s created for them into a [`SmallVec`],
+    /// for code that is generic over the number of resources.
+    ///
+    /// The [`SmallVec`] has an inline capacity of 16, matching the maximum tuple size,
+    /// so it never allocates.
+    ///
+    /// Requires the `smallvec` feature.
+    ///
+    /// [`SmallVec`]: smallvec::SmallVec
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let ids = world.init_resources_vec::<(Score, Lives)>();
+    ///
+    /// assert_eq!(ids.len(), 2);
+    /// assert!(!ids.spilled());
+    /// assert_eq!(world.components().resource_id::<Lives>(), Some(ids[1]));
+    /// ```
+    #[cfg(feature = "smallvec")]
+    fn init_resources_vec<R: InitResources>(&mut self) -> smallvec::SmallVec<[ComponentId; 16]>
+    where
+        R::IDS: IntoIterator<Item = ComponentId>;
+}
+
+impl WorldInitResources for World {
+    #[track_caller]
+    fn init_resources<R: InitResources>(&mut self) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let ids = R::init_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+
+    #[track_caller]
+    fn init_resources_verbose<R: InitResources>(&mut self) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let ids = R::init_resources_verbose(self);
+        end_batch(self, batch);
+        ids
+    }
+
+    #[track_caller]
+    fn init_resources_map<R: InitResources>(&mut self) -> HashMap<TypeId, ComponentId> {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let map = R::init_resources_map(self);
+        end_batch(self, batch);
+        map
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[track_caller]
+    fn init_resources_vec<R: InitResources>(&mut self) -> smallvec::SmallVec<[ComponentId; 16]>
+    where
+        R::IDS: IntoIterator<Item = ComponentId>,
+    {
+        self.init_resources::<R>().into_iter().collect()
+    }
+}
+
+/// Extends [`World`] with `insert_resources_chained` and `init_resources_chained`.
+pub trait WorldChainedResources {
+    /// Inserts the resources like `insert_resources`, but returns the [`World`] instead of the [`ComponentId`]s,
+    /// so calls can be chained like on [`App`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world
+    ///     .insert_resources_chained((Gravity(9.8), Wind(2.0)))
+    ///     .init_resources_chained::<(Score, Lives)>();
+    ///
+    /// assert_eq!(world.resource::<Wind>().0, 2.0);
+    /// assert!(world.contains_resource::<Lives>());
+    /// ```
+    fn insert_resources_chained<R: InsertResources>(&mut self, resources: R) -> &mut Self;
+
+    /// Initializes the resources like `init_resources`, but returns the [`World`] instead of the [`ComponentId`]s,
+    /// so calls can be chained like on [`App`].
+    fn init_resources_chained<R: InitResources>(&mut self) -> &mut Self;
+}
+
+impl WorldChainedResources for World {
+    #[track_caller]
+    fn insert_resources_chained<R: InsertResources>(&mut self, resources: R) -> &mut Self {
+        self.insert_resources(resources);
+        self
+    }
+
+    #[track_caller]
+    fn init_resources_chained<R: InitResources>(&mut self) -> &mut Self {
+        self.init_resources::<R>();
+        self
+    }
+}
+
+/// Extends [`World`] with `try_init_resources`.
+pub trait WorldTryInitResources {
+    /// Initializes new resources with [`TryFromWorld`] and returns the [`ComponentId`]s for them.
+    ///
+    /// Resources that already exist are left as they are, like with `init_resources`.
+    /// If initializing any of the resources fails, the error is returned
+    /// and the resources created by this call are removed again,
+    /// leaving the [`World`] as it was before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, TryFromWorld};
+    /// #
+    /// #[derive(Resource)]
+    /// struct Config(String);
+    ///
+    /// #[derive(Resource)]
+    /// struct Level(u32);
+    ///
+    /// impl TryFromWorld for Config {
+    ///     type Error = String;
+    ///
+    ///     fn try_from_world(_world: &mut World) -> Result<Self, String> {
+    ///         Ok(Config("config.ron".into()))
+    ///     }
+    /// }
+    ///
+    /// impl TryFromWorld for Level {
+    ///     type Error = String;
+    ///
+    ///     fn try_from_world(_world: &mut World) -> Result<Self, String> {
+    ///         Err("missing level asset".into())
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let result = world.try_init_resources::<(Config, Level)>();
+    /// assert_eq!(result.unwrap_err(), "missing level asset");
+    /// assert!(!world.contains_resource::<Config>());
+    ///
+    /// world.insert_resource(Level(1));
+    /// assert!(world.try_init_resources::<(Config, Level)>().is_ok());
+    /// ```
+    fn try_init_resources<R: TryInitResources>(&mut self) -> Result<R::IDS, R::Error>;
+}
+
+impl WorldTryInitResources for World {
+    #[track_caller]
+    fn try_init_resources<R: TryInitResources>(&mut self) -> Result<R::IDS, R::Error> {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let ids = R::try_init_resources(self)?;
+        end_batch(self, batch);
+        Ok(ids)
+    }
+}
+
+/// What `init_resources_validated` does with the resources it created when validation fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnInvalid {
+    /// Keep the resources, so they can be inspected or fixed up.
+    #[default]
+    Keep,
+    /// Remove the resources created by the call, leaving the ones that already existed.
+    RemoveCreated,
+}
+
+/// Extends [`World`] with `init_resources_validated`.
+pub trait WorldInitResourcesValidated {
+    /// Initializes new resources like `init_resources`, then runs `validate` against the [`World`]
+    /// and returns the [`ComponentId`]s if it succeeds.
+    ///
+    /// This is for checking invariants across the group right after setup.
+    /// If `validate` fails, its error is returned and `on_invalid` decides whether the resources are rolled back:
+    /// with [`OnInvalid::Keep`] they're left in the [`World`],
+    /// and with [`OnInvalid::RemoveCreated`] the ones created by this call are removed again.
+    /// Resources that already existed are never removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, OnInvalid};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Health(u32);
+    /// #
+    /// #[derive(Resource)]
+    /// struct MaxHealth(u32);
+    ///
+    /// impl Default for MaxHealth {
+    ///     fn default() -> Self {
+    ///         MaxHealth(100)
+    ///     }
+    /// }
+    ///
+    /// fn validate(world: &World) -> Result<(), String> {
+    ///     let (health, max) = world.get_resources::<(Health, MaxHealth)>().unwrap();
+    ///     if health.0 > max.0 {
+    ///         return Err(format!("health {} exceeds maximum {}", health.0, max.0));
+    ///     }
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Health(150));
+    ///
+    /// let result = world.init_resources_validated::<(Health, MaxHealth)>(OnInvalid::RemoveCreated, validate);
+    /// assert_eq!(result.unwrap_err(), "health 150 exceeds maximum 100");
+    /// assert!(world.contains_resource::<Health>());
+    /// assert!(!world.contains_resource::<MaxHealth>());
+    ///
+    /// world.resource_mut::<Health>().0 = 80;
+    /// assert!(world.init_resources_validated::<(Health, MaxHealth)>(OnInvalid::RemoveCreated, validate).is_ok());
+    /// ```
+    fn init_resources_validated<R: InitResources>(
+        &mut self,
+        on_invalid: OnInvalid,
+        validate: impl FnOnce(&World) -> Result<(), String>,
+    ) -> Result<R::IDS, String>
+    where
+        R::IDS: AsRef<[ComponentId]>;
+}
+
+impl WorldInitResourcesValidated for World {
+    #[track_caller]
+    fn init_resources_validated<R: InitResources>(
+        &mut self,
+        on_invalid: OnInvalid,
+        validate: impl FnOnce(&World) -> Result<(), String>,
+    ) -> Result<R::IDS, String>
+    where
+        R::IDS: AsRef<[ComponentId]>,
+    {
+        let (ids, created) = init_resources_created::<R>(self);
+
+        if let Err(error) = validate(self) {
+            if on_invalid == OnInvalid::RemoveCreated {
+                for (&id, &created) in ids.as_ref().iter().zip(&created) {
+                    if created {
+                        self.remove_resource_by_id(id);
+                    }
+                }
+            }
+            return Err(error);
+        }
+
+        Ok(ids)
+    }
+}
+
+/// Initializes the resources like `init_resources`, also returning whether each of them was created,
+/// in the same order as the [`ComponentId`]s.
+///
+/// Which resources exist is checked up front, so the group is only initialized once.
+#[track_caller]
+fn init_resources_created<R: InitResources>(world: &mut World) -> (R::IDS, Vec<bool>) {
+    let mut types = Vec::new();
+    R::resource_types(&mut types);
+    let created = types
+        .iter()
+        .map(|&(type_id, _)| !contains_resource_type(world, type_id))
+        .collect();
+    (world.init_resources::<R>(), created)
+}
+
+/// Extends [`World`] with `init_resources_typed`.
+pub trait WorldInitResourcesTyped {
+    /// Initializes new resources like `init_resources`,
+    /// but returns a [`ResourceId`] for each resource instead of an array of [`ComponentId`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, ResourceId};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let (score, lives) = world.init_resources_typed::<(Score, Lives)>();
+    ///
+    /// // Tracking code can require the id of a specific resource.
+    /// fn track_score(id: ResourceId<Score>) -> bevy_ecs::component::ComponentId {
+    ///     id.id()
+    /// }
+    ///
+    /// assert_eq!(world.components().resource_id::<Score>(), Some(track_score(score)));
+    /// assert_eq!(world.components().resource_id::<Lives>(), Some(lives.into()));
+    /// ```
+    fn init_resources_typed<R: InitResourcesTyped>(&mut self) -> R::TypedIds;
+}
+
+impl WorldInitResourcesTyped for World {
+    #[track_caller]
+    fn init_resources_typed<R: InitResourcesTyped>(&mut self) -> R::TypedIds {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let ids = R::init_resources_typed(self);
+        end_batch(self, batch);
+        ids
+    }
+}
+
+/// Extends [`World`] with `init_resources_mut`.
+pub trait WorldInitResourcesMut {
+    /// Initializes new resources and returns mutable references to all of them.
+    ///
+    /// If a resource already exists, it isn't initialized again, but a reference to it is still returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the same resource type appears more than once in the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    ///
+    /// let (mut score, mut lives) = world.init_resources_mut::<(Score, Lives)>();
+    /// score.0 = 10;
+    /// lives.0 = 3;
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert_eq!(world.resource::<Lives>().0, 3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.init_resources_mut::<(Score, Score)>();
+    /// ```
+    fn init_resources_mut<R: InitResourcesMut>(&mut self) -> R::Muts<'_>;
+}
+
+impl WorldInitResourcesMut for World {
+    #[track_caller]
+    fn init_resources_mut<R: InitResourcesMut>(&mut self) -> R::Muts<'_> {
+        // The guards borrow the world, so the batch is recorded before initializing.
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        end_batch(self, batch);
+        R::init_resources_mut(self)
+    }
+}
+
+/// Extends [`World`] with `init_resources_default`.
+pub trait WorldInitDefaultResources {
+    /// Initializes new resources with their [`Default`] values and returns the [`ComponentId`]s created for them.
+    ///
+    /// If a resource already exists, nothing happens.
+    ///
+    /// This is the same as `init_resources`, except that every resource must implement [`Default`],
+    /// so resources with a custom [`FromWorld`] implementation are rejected at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.init_resources_default::<(Score, Lives)>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// #[derive(Resource)]
+    /// struct Level(u32);
+    ///
+    /// impl FromWorld for Level {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         Level(world.resource::<Score>().0 / 100)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resources_default::<(Score, Level)>();
+    /// ```
+    fn init_resources_default<R: DefaultResources>(&mut self) -> R::IDS;
+}
+
+impl WorldInitDefaultResources for World {
+    #[track_caller]
+    fn init_resources_default<R: DefaultResources>(&mut self) -> R::IDS {
+        self.init_resources::<R>()
+    }
+}
+
+/// Extends [`World`] with `init_resources_report`.
+pub trait WorldInitResourcesReport {
+    /// Initializes new resources like `init_resources`, and reports which ones were created.
+    ///
+    /// The returned array is in the same order as the tuple,
+    /// with `true` for resources that were newly created and `false` for ones that already existed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// assert_eq!(world.init_resources_report::<(Score, Lives)>(), [true, false]);
+    /// assert_eq!(world.resource::<Lives>().0, 3);
+    /// ```
+    fn init_resources_report<R: InitResources>(&mut self) -> R::Report;
+}
+
+impl WorldInitResourcesReport for World {
+    #[track_caller]
+    fn init_resources_report<R: InitResources>(&mut self) -> R::Report {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let report = R::init_resources_report(self);
+        end_batch(self, batch);
+        report
+    }
+}
+
+/// Extends [`World`] with `init_resources_two_phase`.
+pub trait WorldInitResourcesTwoPhase {
+    /// Initializes new resources like `init_resources`,
+    /// but registers all of their [`ComponentId`]s before running any [`FromWorld`] implementations.
+    ///
+    /// With `init_resources`, each resource is registered right before it's initialized,
+    /// so a [`FromWorld`] implementation can only look up the ids of the resources before it in the tuple.
+    /// Here every id in the group exists by the time the first one runs,
+    /// although the resources after it still don't have values yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{component::ComponentId, prelude::*};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// #[derive(Resource)]
+    /// struct Watcher(Option<ComponentId>);
+    ///
+    /// impl FromWorld for Watcher {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         Watcher(world.components().resource_id::<Config>())
+    ///     }
+    /// }
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Config(u32);
+    ///
+    /// let mut world = World::new();
+    /// let [_, config] = world.init_resources_two_phase::<(Watcher, Config)>();
+    /// assert_eq!(world.resource::<Watcher>().0, Some(config));
+    /// ```
+    fn init_resources_two_phase<R: InitResources>(&mut self) -> R::IDS;
+}
+
+impl WorldInitResourcesTwoPhase for World {
+    #[track_caller]
+    fn init_resources_two_phase<R: InitResources>(&mut self) -> R::IDS {
+        R::register_resource_ids(self);
+        self.init_resources::<R>()
+    }
+}
+
+/// Extends [`World`] with `insert_resources_defer_drop`.
+pub trait WorldInsertResourcesDeferDrop {
+    /// Inserts new resources like `insert_resources`, but returns the values they overwrite
+    /// instead of dropping them inline.
+    ///
+    /// This moves the cost of destructors for large resources, like big buffers, out of the insertion,
+    /// so they can be dropped at a better time, such as at the end of the frame or on another thread.
+    /// Resources that didn't exist yet don't add anything to the returned [`DeferredDrops`].
+    ///
+    /// See [`DeferredDrops`] for an example.
+    fn insert_resources_defer_drop<R: InsertResources>(&mut self, resources: R) -> DeferredDrops;
+}
+
+impl WorldInsertResourcesDeferDrop for World {
+    #[track_caller]
+    fn insert_resources_defer_drop<R: InsertResources>(&mut self, resources: R) -> DeferredDrops {
+        let mut drops = DeferredDrops::default();
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        resources.insert_resources_defer_drop(self, &mut drops);
+        end_batch(self, batch);
+        drops
+    }
+}
+
+/// Extends [`World`] with `init_resources_at_tick`.
+pub trait WorldInitResourcesAtTick {
+    /// Initializes new resources like `init_resources`, then sets the changed tick of every resource in the group to `tick`.
+    ///
+    /// This makes change detection in tests independent of how many systems ran before,
+    /// for example when replaying a simulation.
+    /// Resources in the group that already existed are stamped too, so the whole group ends up with the same tick.
+    ///
+    /// Bevy only exposes setting the changed tick, through [`DetectChangesMut::set_last_changed`],
+    /// so the added tick of new resources is still the world's current change tick,
+    /// and `is_added` isn't affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.init_resources_at_tick::<(Score, Lives)>(5);
+    ///
+    /// assert_eq!(world.resource_mut::<Score>().last_changed(), 5);
+    /// assert_eq!(world.resource_mut::<Lives>().last_changed(), 5);
+    /// ```
+    fn init_resources_at_tick<R: InitResources>(&mut self, tick: u32) -> R::IDS;
+}
+
+impl WorldInitResourcesAtTick for World {
+    #[track_caller]
+    fn init_resources_at_tick<R: InitResources>(&mut self, tick: u32) -> R::IDS {
+        let ids = self.init_resources::<R>();
+
+        let mut types = Vec::new();
+        R::resource_types(&mut types);
+        for (type_id, _) in types {
+            let id = self.components().get_resource_id(type_id).unwrap();
+            if let Some(mut resource) = self.get_resource_mut_by_id(id) {
+                resource.set_last_changed(tick);
+            }
+        }
+
+        ids
+    }
+}
+
+/// Extends [`World`] with `insert_resources_report`.
+pub trait WorldInsertResourcesReport {
+    /// Inserts new resources like `insert_resources`, and reports which ones overwrote an existing value.
+    ///
+    /// The returned array is in the same order as the tuple,
+    /// with `true` for resources that already existed and were overwritten and `false` for ones that were newly added.
+    /// This is the opposite of `init_resources_report`, which reports `true` for the resources it created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// let overwritten = world.insert_resources_report((Score(0), Lives(5)));
+    /// assert_eq!(overwritten, [false, true]);
+    ///
+    /// let count = overwritten.iter().filter(|overwritten| **overwritten).count();
+    /// println!("overwrote {count} existing, added {} new", overwritten.len() - count);
+    /// ```
+    fn insert_resources_report<R: InsertResources>(&mut self, resources: R) -> R::Inserted;
+}
+
+impl WorldInsertResourcesReport for World {
+    #[track_caller]
+    fn insert_resources_report<R: InsertResources>(&mut self, resources: R) -> R::Inserted {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let overwritten = resources.insert_resources_report(self);
+        end_batch(self, batch);
+        overwritten
+    }
+}
+
+/// Extends [`World`] with `ensure_resources`.
+pub trait WorldEnsureResources {
+    /// Initializes all of the resources if none of them exist yet, returning `true` if they were created.
+    ///
+    /// Unlike `init_resources`, this doesn't fill in missing resources when some of them already exist;
+    /// if any of the resources are present, the world is left untouched and `false` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// assert!(!world.ensure_resources::<(Score, Lives)>());
+    /// assert!(!world.contains_resource::<Score>());
+    ///
+    /// world.remove_resource::<Lives>();
+    ///
+    /// assert!(world.ensure_resources::<(Score, Lives)>());
+    /// assert!(world.contains_resource::<Score>() && world.contains_resource::<Lives>());
+    /// ```
+    fn ensure_resources<R: InitResources + ContainsResources>(&mut self) -> bool;
+}
+
+impl WorldEnsureResources for World {
+    #[track_caller]
+    fn ensure_resources<R: InitResources + ContainsResources>(&mut self) -> bool {
+        if self.contains_any_resources::<R>() {
+            return false;
+        }
+        self.init_resources::<R>();
+        true
+    }
+}
+
+/// Restores the resource replaced by `init_resources_seeded` when dropped, even while unwinding.
+struct SeedGuard<'w, C: Resource> {
+    world: &'w mut World,
+    previous: Option<C>,
+}
+
+impl<C: Resource> Drop for SeedGuard<'_, C> {
+    fn drop(&mut self) {
+        self.world.remove_resource::<C>();
+        if let Some(previous) = self.previous.take() {
+            self.world.insert_resource(previous);
+        }
+    }
+}
+
+/// Extends [`World`] with `init_resources_seeded`.
+pub trait WorldInitResourcesSeeded {
+    /// Initializes new resources like `init_resources`, with `seed` inserted as a resource
+    /// for the duration of the call so [`FromWorld`] implementations can read it.
+    ///
+    /// Afterwards, the seed is removed and any value it replaced is restored, even if initialization panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// #[derive(Resource)]
+    /// struct Config {
+    ///     lives: u32,
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Lives(u32);
+    ///
+    /// impl FromWorld for Lives {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         Lives(world.resource::<Config>().lives)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Config { lives: 3 });
+    ///
+    /// world.init_resources_seeded::<(Lives,), _>(Config { lives: 1 });
+    ///
+    /// assert_eq!(world.resource::<Lives>().0, 1);
+    /// assert_eq!(world.resource::<Config>().lives, 3);
+    /// ```
+    fn init_resources_seeded<R: InitResources, C: Resource>(&mut self, seed: C) -> R::IDS;
+}
+
+impl WorldInitResourcesSeeded for World {
+    #[track_caller]
+    fn init_resources_seeded<R: InitResources, C: Resource>(&mut self, seed: C) -> R::IDS {
+        let previous = self.remove_resource::<C>();
+        self.insert_resource(seed);
+        let guard = SeedGuard {
+            world: self,
+            previous,
+        };
+        guard.world.init_resources::<R>()
+    }
+}
+
+/// Extends [`App`] with `init_resources`.
+pub trait AppInitResources {
+    /// Initialize a [`Resource`] with standard starting values by adding it to the [`World`].
+    ///
+    /// If the [`Resource`] already exists, nothing happens.
+    ///
+    /// The [`Resource`] must implement the [`FromWorld`] trait.
+    /// If the [`Default`] trait is implemented, the [`FromWorld`] trait will use
+    /// the [`Default::default`] method to initialize the [`Resource`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// #[derive(Resource)]
+    /// struct MyCounter {
+    ///     counter: usize,
+    /// }
+    ///
+    /// impl Default for MyCounter {
+    ///     fn default() -> MyCounter {
+    ///         MyCounter {
+    ///             counter: 100
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct MyValue {
+    ///     value: f32,
+    /// }
+    ///
+    /// impl Default for MyValue {
+    ///     fn default() -> MyValue {
+    ///         MyValue {
+    ///             value: 20.0
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .init_resources::<(MyCounter, MyValue)>();
+    /// ```
+    ///
+    /// # Ordering
+    ///
+    /// Resources must be initialized before [`App::run`] is called, usually while building plugins.
+    /// [`App::run`] moves everything out of the [`App`] and leaves an empty one in its place,
+    /// so resources initialized on it afterwards are added to that empty [`App`] and never seen by any system.
+    /// There's no way to detect this from the [`App`] itself, so it isn't checked.
+    fn init_resources<R: InitResources>(&mut self) -> &mut Self;
+
+    /// Initializes [`Resource`]s like [`init_resources`](Self::init_resources),
+    /// but logs a warning for every [`Resource`] that already exists and is therefore skipped.
+    ///
+    /// This is useful for finding plugins that initialize the same [`Resource`]s.
+    fn init_resources_verbose<R: InitResources>(&mut self) -> &mut Self;
+
+    /// Initializes [`Resource`]s like [`init_resources`](Self::init_resources),
+    /// but returns the [`ComponentId`]s created for them instead of the [`App`].
+    ///
+    /// Since the [`App`] isn't returned, this can't be chained with other builder methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyCounter(usize);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyValue(f32);
+    /// #
+    /// let mut app = App::new();
+    /// let [counter, value] = app.init_resources_get::<(MyCounter, MyValue)>();
+    /// ```
+    fn init_resources_get<R: InitResources>(&mut self) -> R::IDS;
+
+    /// Initializes [`Resource`]s like [`init_resources`](Self::init_resources),
+    /// but in the [`World`] of the `SubApp` with the given label, such as the render app.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `SubApp` doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, AppLabel, SubApp};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyCounter(usize);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyValue(f32);
+    /// #
+    /// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySubApp;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_sub_app(MySubApp, SubApp::new(App::empty(), |_, _| {}));
+    ///
+    /// app.init_resources_in::<(MyCounter, MyValue)>(MySubApp);
+    /// assert!(app.sub_app(MySubApp).world.contains_resource::<MyCounter>());
+    /// assert!(!app.world.contains_resource::<MyCounter>());
+    /// ```
+    fn init_resources_in<R: InitResources>(&mut self, label: impl AppLabel) -> &mut Self;
+
+    /// Adds a system to the schedule with the given label that initializes the [`Resource`]s
+    /// every time the schedule runs.
+    ///
+    /// Since initializing a resource that already exists does nothing, this only creates the resources
+    /// the first time the schedule runs, or after they've been removed.
+    /// It ensures the resources are present when the schedule starts, but doesn't reset their values;
+    /// for that, remove them in an earlier schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Scratch(Vec<u32>);
+    /// #
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Prepare;
+    ///
+    /// let mut app = App::new();
+    /// app.init_schedule(Prepare)
+    ///     .init_resources_in_schedule::<(Scratch,)>(Prepare);
+    /// assert!(!app.world.contains_resource::<Scratch>());
+    ///
+    /// app.world.run_schedule(Prepare);
+    /// assert!(app.world.contains_resource::<Scratch>());
+    /// ```
+    fn init_resources_in_schedule<R: InitResources>(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> &mut Self;
+
+    /// Initializes [`Resource`]s like [`init_resources`](Self::init_resources),
+    /// but only if `predicate` returns `true`.
+    ///
+    /// The predicate is checked once, right away, against the [`World`] as it is when this is called,
+    /// so it only sees resources added earlier in the builder chain or by plugins added before.
+    /// It isn't checked again later; use run conditions for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct DebugTools;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Inspector(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct FrameGraph(Vec<f32>);
+    /// #
+    /// let mut app = App::new();
+    /// app.init_resources_if::<(Inspector,)>(|world| world.contains_resource::<DebugTools>())
+    ///     .insert_resource(DebugTools)
+    ///     .init_resources_if::<(FrameGraph,)>(|world| world.contains_resource::<DebugTools>());
+    ///
+    /// assert!(!app.world.contains_resource::<Inspector>());
+    /// assert!(app.world.contains_resource::<FrameGraph>());
+    /// ```
+    fn init_resources_if<R: InitResources>(
+        &mut self,
+        predicate: impl FnOnce(&World) -> bool,
+    ) -> &mut Self;
+
+    /// Adds a startup system in the system set `set` that initializes [`Resource`]s like [`init_resources`](Self::init_resources).
+    ///
+    /// Other startup systems can be ordered `.after` or `.before` the set,
+    /// so setup that depends on the resources doesn't need to happen eagerly while building the [`App`].
+    /// The system is exclusive, so [`FromWorld`] implementations have full access to the [`World`].
+    /// Like with `in_set`, `set` can't be a base set such as [`StartupSet`](bevy_app::StartupSet);
+    /// the system always runs in [`StartupSet::Startup`](bevy_app::StartupSet::Startup).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Tilemap(Vec<u8>);
+    /// #
+    /// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MapSetup;
+    ///
+    /// fn spawn_tiles(tilemap: Res<Tilemap>) {
+    ///     // ...
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_init_resources_system::<(Tilemap,)>(MapSetup)
+    ///     .add_startup_system(spawn_tiles.after(MapSetup));
+    /// assert!(!app.world.contains_resource::<Tilemap>());
+    ///
+    /// app.update();
+    /// assert!(app.world.contains_resource::<Tilemap>());
+    /// ```
+    fn add_init_resources_system<R: InitResources>(&mut self, set: impl FreeSystemSet)
+        -> &mut Self;
+}
+
+impl AppInitResources for App {
+    #[track_caller]
+    fn init_resources<R: InitResources>(&mut self) -> &mut Self {
+        self.world.init_resources::<R>();
+        self
+    }
+
+    #[track_caller]
+    fn init_resources_verbose<R: InitResources>(&mut self) -> &mut Self {
+        self.world.init_resources_verbose::<R>();
+        self
+    }
+
+    #[track_caller]
+    fn init_resources_get<R: InitResources>(&mut self) -> R::IDS {
+        self.world.init_resources::<R>()
+    }
+
+    #[track_caller]
+    fn init_resources_in<R: InitResources>(&mut self, label: impl AppLabel) -> &mut Self {
+        self.sub_app_mut(label).world.init_resources::<R>();
+        self
+    }
+
+    fn init_resources_in_schedule<R: InitResources>(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.add_system(
+            (|world: &mut World| {
+                world.init_resources::<R>();
+            })
+            .in_schedule(label),
+        )
+    }
+
+    fn add_init_resources_system<R: InitResources>(
+        &mut self,
+        set: impl FreeSystemSet,
+    ) -> &mut Self {
+        self.add_startup_system(
+            (|world: &mut World| {
+                world.init_resources::<R>();
+            })
+            .in_set(set),
+        )
+    }
+
+    #[track_caller]
+    fn init_resources_if<R: InitResources>(
+        &mut self,
+        predicate: impl FnOnce(&World) -> bool,
+    ) -> &mut Self {
+        if predicate(&self.world) {
+            self.world.init_resources::<R>();
+        }
+        self
+    }
+}
+
+/// Returns an exclusive system that initializes the resources and outputs their [`ComponentId`]s.
+///
+/// This turns `init_resources` into a scheduling unit, so the ids can be piped into a follow-up system.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::{component::ComponentId, prelude::*};
+/// # use bevy_proto_resource_tuples::init_resources_system;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Lives(u32);
+/// #
+/// fn log_ids(In([score, lives]): In<[ComponentId; 2]>) {
+///     println!("initialized Score as {score:?} and Lives as {lives:?}");
+/// }
+///
+/// let mut app = App::new();
+/// app.add_startup_system(init_resources_system::<(Score, Lives)>().pipe(log_ids));
+/// app.update();
+///
+/// assert!(app.world.contains_resource::<Lives>());
+/// ```
+pub fn init_resources_system<R: InitResources>() -> impl System<In = (), Out = R::IDS> {
+    IntoSystem::into_system(|world: &mut World| world.init_resources::<R>())
+}
+
+/// [`Plugin`] that initializes the resources when added to an [`App`].
+///
+/// This lets a group of resources be shared like any other plugin.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::InitResourcesPlugin;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct MusicVolume(f32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct SfxVolume(f32);
+/// #
+/// let mut app = App::new();
+/// app.add_plugin(InitResourcesPlugin::<(MusicVolume, SfxVolume)>::new());
+///
+/// assert!(app.world.contains_resource::<SfxVolume>());
+/// ```
+pub struct InitResourcesPlugin<R: InitResources> {
+    _phantom: PhantomData<R>,
+}
+
+impl<R: InitResources> fmt::Debug for InitResourcesPlugin<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitResourcesPlugin")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: InitResources> Plugin for InitResourcesPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.init_resources::<R>();
+    }
+}
+
+impl<R: InitResources> Default for InitResourcesPlugin<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: InitResources> InitResourcesPlugin<R> {
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData::<R>,
+        }
+    }
+}
+
+/// [`Plugin`] that inserts the resources when added to an [`App`].
+///
+/// [`Plugin::build`] only borrows the plugin, and Bevy keeps the plugin around after building it,
+/// so the resources are cloned into the [`App`] rather than moved, which requires them to implement [`Clone`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::InsertResourcesPlugin;
+/// #
+/// # #[derive(Resource, Clone)]
+/// # struct MusicVolume(f32);
+/// #
+/// # #[derive(Resource, Clone)]
+/// # struct SfxVolume(f32);
+/// #
+/// let mut app = App::new();
+/// app.add_plugin(InsertResourcesPlugin::new((MusicVolume(0.5), SfxVolume(1.0))));
+///
+/// assert_eq!(app.world.resource::<MusicVolume>().0, 0.5);
+/// ```
+pub struct InsertResourcesPlugin<R: InsertResources + Clone> {
+    pub resources: R,
+}
+
+impl<R: InsertResources + Clone> fmt::Debug for InsertResourcesPlugin<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InsertResourcesPlugin")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: InsertResources + Clone> Plugin for InsertResourcesPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.insert_resources(self.resources.clone());
+    }
+}
+
+impl<R: InsertResources + Clone> InsertResourcesPlugin<R> {
+    pub const fn new(resources: R) -> Self {
+        Self { resources }
+    }
+}
+
+/// Resources that can be constructed together from a loaded asset.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy::reflect::{self as bevy_reflect, TypeUuid};
+/// # use bevy_proto_resource_tuples::FromAsset;
+/// #
+/// # #[derive(Resource)]
+/// # struct Gravity(f32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Wind(f32);
+/// #
+/// #[derive(TypeUuid)]
+/// #[uuid = "8c2d6a4e-4c1e-4f5b-9d0a-6f3b2e1c7a90"]
+/// struct PhysicsConfig {
+///     gravity: f32,
+///     wind: f32,
+/// }
+///
+/// impl FromAsset<PhysicsConfig> for (Gravity, Wind) {
+///     fn from_asset(config: &PhysicsConfig) -> Self {
+///         (Gravity(config.gravity), Wind(config.wind))
+///     }
+/// }
+/// ```
+#[cfg(feature = "asset")]
+pub trait FromAsset<A: bevy_asset::Asset>: InsertResources + Sized {
+    fn from_asset(asset: &A) -> Self;
+}
+
+/// Extends [`App`] with `init_resources_from_asset` and `init_resources_when_loaded`.
+#[cfg(feature = "asset")]
+pub trait AppInitResourcesFromAsset {
+    /// Adds a system that waits for the asset to load and then inserts the resources constructed from it with [`FromAsset`].
+    ///
+    /// The resources are inserted once, during the first update after the asset is available,
+    /// so systems that need them should wait for them to exist.
+    /// Later changes to the asset aren't applied.
+    ///
+    /// Requires the `asset` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::{asset::AssetPlugin, prelude::*, reflect::{self as bevy_reflect, TypeUuid}};
+    /// # use bevy_proto_resource_tuples::{prelude::*, FromAsset};
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// # #[derive(TypeUuid)]
+    /// # #[uuid = "8c2d6a4e-4c1e-4f5b-9d0a-6f3b2e1c7a90"]
+    /// # struct PhysicsConfig {
+    /// #     gravity: f32,
+    /// #     wind: f32,
+    /// # }
+    /// #
+    /// # impl FromAsset<PhysicsConfig> for (Gravity, Wind) {
+    /// #     fn from_asset(config: &PhysicsConfig) -> Self {
+    /// #         (Gravity(config.gravity), Wind(config.wind))
+    /// #     }
+    /// # }
+    /// #
+    /// let mut app = App::new();
+    /// app.add_plugins(MinimalPlugins)
+    ///     .add_plugin(AssetPlugin::default())
+    ///     .add_asset::<PhysicsConfig>();
+    ///
+    /// // Usually this would come from `AssetServer::load`.
+    /// let handle = app
+    ///     .world
+    ///     .resource_mut::<Assets<PhysicsConfig>>()
+    ///     .add(PhysicsConfig { gravity: 9.8, wind: 2.0 });
+    ///
+    /// app.init_resources_from_asset::<(Gravity, Wind), _>(handle);
+    /// assert!(!app.world.contains_resource::<Gravity>());
+    ///
+    /// app.update();
+    /// assert_eq!(app.world.resource::<Gravity>().0, 9.8);
+    /// ```
+    fn init_resources_from_asset<R: FromAsset<A>, A: bevy_asset::Asset>(
+        &mut self,
+        handle: bevy_asset::Handle<A>,
+    ) -> &mut Self;
+
+    /// Adds a system that waits for the asset to load and then initializes the resources with [`FromWorld`].
+    ///
+    /// Unlike `init_resources_from_asset`, the asset doesn't produce the resources,
+    /// it only delays `init_resources` until it's available, so [`FromWorld`] implementations can read it.
+    /// The resources are initialized once, during the first update after the asset is available.
+    ///
+    /// If the [`AssetServer`] reports that the asset failed to load, an error is logged
+    /// and the resources aren't initialized.
+    ///
+    /// Bevy 0.10 can't remove systems, so the system stays in the schedule afterwards,
+    /// returning immediately on every update.
+    ///
+    /// Requires the `asset` feature.
+    ///
+    /// [`AssetServer`]: bevy_asset::AssetServer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::{asset::AssetPlugin, prelude::*, reflect::{self as bevy_reflect, TypeUuid}};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(TypeUuid)]
+    /// # #[uuid = "3f6a1d2c-9b8e-4a7d-8c5f-1e2d3c4b5a60"]
+    /// # struct LevelData {
+    /// #     spawn_count: u32,
+    /// # }
+    /// #
+    /// #[derive(Resource)]
+    /// struct LevelHandle(Handle<LevelData>);
+    ///
+    /// #[derive(Resource)]
+    /// struct SpawnBudget(u32);
+    ///
+    /// impl FromWorld for SpawnBudget {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         let handle = &world.resource::<LevelHandle>().0;
+    ///         let level = world.resource::<Assets<LevelData>>().get(handle).unwrap();
+    ///         SpawnBudget(level.spawn_count * 2)
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(MinimalPlugins)
+    ///     .add_plugin(AssetPlugin::default())
+    ///     .add_asset::<LevelData>();
+    ///
+    /// // Usually this would come from `AssetServer::load`.
+    /// let handle = app
+    ///     .world
+    ///     .resource_mut::<Assets<LevelData>>()
+    ///     .add(LevelData { spawn_count: 5 });
+    ///
+    /// app.insert_resource(LevelHandle(handle.clone()))
+    ///     .init_resources_when_loaded::<(SpawnBudget,), _>(handle);
+    /// assert!(!app.world.contains_resource::<SpawnBudget>());
+    ///
+    /// app.update();
+    /// assert_eq!(app.world.resource::<SpawnBudget>().0, 10);
+    /// ```
+    fn init_resources_when_loaded<R: InitResources, A: bevy_asset::Asset>(
+        &mut self,
+        handle: bevy_asset::Handle<A>,
+    ) -> &mut Self;
+}
+
+#[cfg(feature = "asset")]
+impl AppInitResourcesFromAsset for App {
+    fn init_resources_from_asset<R: FromAsset<A>, A: bevy_asset::Asset>(
+        &mut self,
+        handle: bevy_asset::Handle<A>,
+    ) -> &mut Self {
+        let mut inserted = false;
+        self.add_system(move |world: &mut World| {
+            if inserted {
+                return;
+            }
+            let resources = world
+                .get_resource::<bevy_asset::Assets<A>>()
+                .and_then(|assets| assets.get(&handle))
+                .map(R::from_asset);
+            if let Some(resources) = resources {
+                world.insert_resources(resources);
+                inserted = true;
+            }
+        })
+    }
+
+    fn init_resources_when_loaded<R: InitResources, A: bevy_asset::Asset>(
+        &mut self,
+        handle: bevy_asset::Handle<A>,
+    ) -> &mut Self {
+        let mut done = false;
+        self.add_system(move |world: &mut World| {
+            if done {
+                return;
+            }
+            let loaded = world
+                .get_resource::<bevy_asset::Assets<A>>()
+                .is_some_and(|assets| assets.contains(&handle));
+            if loaded {
+                world.init_resources::<R>();
+                done = true;
+                return;
+            }
+            let failed = world
+                .get_resource::<bevy_asset::AssetServer>()
+                .is_some_and(|server| {
+                    server.get_load_state(&handle) == bevy_asset::LoadState::Failed
+                });
+            if failed {
+                bevy_log::error!(
+                    "asset failed to load, so `{}` won't be initialized",
+                    std::any::type_name::<R>()
+                );
+                done = true;
+            }
+        })
+    }
+}
+
+/// The number of resources initialized and inserted through the batch `init_*` and `insert_*` methods,
+/// counted while [`ResourceTupleDiagnosticsPlugin`] is added.
+///
+/// This includes the resources added through [`App`] and [`Commands`],
+/// and the ones written back by methods like `update_resources`, `reset_resources` and `move_resources`.
+/// Resources that were skipped because they already existed aren't counted,
+/// but inserted values that overwrite an existing resource are.
+/// Groups that mix values and initialized resources, like `insert_or_init_resources`, count as inserted.
+#[cfg(feature = "diagnostic")]
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceTupleCounts {
+    pub initialized: usize,
+    pub inserted: usize,
+}
+
+/// A timeline of the resources initialized and inserted through the batch `init_*` and `insert_*` methods,
+/// recorded while it's present, for debugging the order plugins set up resources in.
+///
+/// Each entry is the [`TypeId`] and type name of a resource, along with the world's change tick when it was added.
+/// Nothing is recorded while the log is absent, so it costs a single resource lookup per call when disabled.
+/// Add it with [`AppResourceAudit::enable_resource_audit`], or insert it into a [`World`] directly.
+///
+/// This includes the resources added through [`App`] and [`Commands`],
+/// the ones written back by methods like `update_resources`, `reset_resources` and `move_resources`,
+/// and resources that were skipped because they already existed.
+/// Resources added one at a time with `init_resource` or `insert_resource` aren't recorded.
+///
+/// The change tick only advances when systems run, so everything added while building the [`App`]
+/// shares a tick and is kept in the order it was added.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_proto_resource_tuples::{prelude::*, ResourceInitLog};
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Lives(u32);
+/// #
+/// let mut app = App::new();
+/// app.enable_resource_audit()
+///     .init_resources::<(Score,)>()
+///     .insert_resources((Lives(3),));
+///
+/// let log = app.world.resource::<ResourceInitLog>();
+/// assert_eq!(log.0.len(), 2);
+/// println!("{}", log.dump());
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResourceInitLog(pub Vec<(TypeId, &'static str, u32)>);
+
+impl ResourceInitLog {
+    /// Returns the entries sorted by change tick.
+    ///
+    /// Entries with the same tick keep the order they were recorded in.
+    pub fn sorted_by_tick(&self) -> Vec<(TypeId, &'static str, u32)> {
+        let mut entries = self.0.clone();
+        entries.sort_by_key(|&(_, _, tick)| tick);
+        entries
+    }
+
+    /// Formats the entries sorted by change tick, one per line.
+    pub fn dump(&self) -> String {
+        self.sorted_by_tick()
+            .into_iter()
+            .map(|(_, name, tick)| format!("{tick:>10} {name}\n"))
+            .collect()
+    }
+}
+
+/// How a batch of resources is added, which decides how [`ResourceTupleCounts`] counts it.
+#[derive(Clone, Copy)]
+enum BatchKind {
+    /// Resources that already exist are skipped, so only the missing ones count as initialized.
+    Init,
+    /// Every resource counts as inserted, including the ones that overwrite a value.
+    Insert,
+    /// Resources that already exist are skipped, so only the missing ones count as inserted.
+    InsertIfAbsent,
+}
+
+/// A batch of resources being initialized or inserted,
+/// recorded in [`ResourceTupleCounts`] and [`ResourceInitLog`] once it's done.
+///
+/// Every method on [`World`] that adds or writes back a group of resources starts one with [`begin_batch`]
+/// and finishes it with [`end_batch`], either directly or by calling another such method,
+/// so the counts and the log cover the same calls.
+struct ResourceBatch {
+    #[cfg(feature = "diagnostic")]
+    counts: ResourceTupleCounts,
+    types: Vec<(TypeId, &'static str)>,
+}
+
+/// Lists the resources of a batch before it runs, or returns `None` if there's nothing to record them in.
+///
+/// Whether each resource exists is checked here, before the batch adds anything,
+/// so the counts only include the resources the batch creates.
+fn begin_batch(
+    world: &World,
+    kind: BatchKind,
+    types: impl FnOnce(&mut Vec<(TypeId, &'static str)>),
+) -> Option<ResourceBatch> {
+    #[cfg(feature = "diagnostic")]
+    let counting = world.contains_resource::<ResourceTupleCounts>();
+    #[cfg(not(feature = "diagnostic"))]
+    let counting = {
+        let _ = kind;
+        false
+    };
+    if !counting && !world.contains_resource::<ResourceInitLog>() {
+        return None;
+    }
+
+    let mut found = Vec::new();
+    types(&mut found);
+
+    #[cfg(feature = "diagnostic")]
+    let counts = {
+        // Groups like `[R; N]` list the same resource more than once, but only add it once.
+        let mut distinct: Vec<TypeId> = found.iter().map(|&(type_id, _)| type_id).collect();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let missing = || {
+            distinct
+                .iter()
+                .filter(|&&type_id| !contains_resource_type(world, type_id))
+                .count()
+        };
+        match kind {
+            BatchKind::Init => ResourceTupleCounts {
+                initialized: missing(),
+                inserted: 0,
+            },
+            BatchKind::Insert => ResourceTupleCounts {
+                initialized: 0,
+                inserted: distinct.len(),
+            },
+            BatchKind::InsertIfAbsent => ResourceTupleCounts {
+                initialized: 0,
+                inserted: missing(),
+            },
+        }
+    };
+
+    Some(ResourceBatch {
+        #[cfg(feature = "diagnostic")]
+        counts,
+        types: found,
+    })
+}
+
+fn contains_resource_type(world: &World, type_id: TypeId) -> bool {
+    world
+        .components()
+        .get_resource_id(type_id)
+        .is_some_and(|id| world.get_resource_by_id(id).is_some())
+}
+
+/// Records a batch started with [`begin_batch`], after its resources were added.
+fn end_batch(world: &mut World, batch: Option<ResourceBatch>) {
+    let Some(batch) = batch else {
+        return;
+    };
+
+    #[cfg(feature = "diagnostic")]
+    if let Some(mut counts) = world.get_resource_mut::<ResourceTupleCounts>() {
+        counts.initialized += batch.counts.initialized;
+        counts.inserted += batch.counts.inserted;
+    }
+
+    let tick = world.read_change_tick();
+    if let Some(mut log) = world.get_resource_mut::<ResourceInitLog>() {
+        log.0
+            .extend(batch.types.into_iter().map(|(id, name)| (id, name, tick)));
+    }
+}
+
+/// Extends [`App`] with `enable_resource_audit`.
+pub trait AppResourceAudit {
+    /// Adds an empty [`ResourceInitLog`], so every later batch `init_*` and `insert_*` call is recorded in it.
+    ///
+    /// Does nothing if the log already exists.
+    fn enable_resource_audit(&mut self) -> &mut Self;
+}
+
+impl AppResourceAudit for App {
+    fn enable_resource_audit(&mut self) -> &mut Self {
+        self.init_resource::<ResourceInitLog>()
+    }
+}
+
+/// [`Plugin`] that adds diagnostics for the number of resources initialized and inserted
+/// through the batch `init_*` and `insert_*` methods.
+///
+/// Only resources added after the plugin are counted.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_diagnostic::Diagnostics;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::{prelude::*, ResourceTupleDiagnosticsPlugin};
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct MusicVolume(f32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct SfxVolume(f32);
+/// #
+/// let mut app = App::new();
+/// app.add_plugin(ResourceTupleDiagnosticsPlugin)
+///     .init_resources::<(MusicVolume, SfxVolume)>();
+/// app.update();
+///
+/// let diagnostics = app.world.resource::<Diagnostics>();
+/// let initialized = diagnostics.get(ResourceTupleDiagnosticsPlugin::INITIALIZED).unwrap();
+/// assert_eq!(initialized.value(), Some(2.0));
+/// ```
+#[cfg(feature = "diagnostic")]
+#[derive(Debug, Default)]
+pub struct ResourceTupleDiagnosticsPlugin;
+
+#[cfg(feature = "diagnostic")]
+impl Plugin for ResourceTupleDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<bevy_diagnostic::Diagnostics>()
+            .init_resource::<ResourceTupleCounts>()
+            .add_startup_system(Self::setup_system)
+            .add_system(Self::diagnostic_system);
+    }
+}
+
+#[cfg(feature = "diagnostic")]
+impl ResourceTupleDiagnosticsPlugin {
+    pub const INITIALIZED: bevy_diagnostic::DiagnosticId =
+        bevy_diagnostic::DiagnosticId::from_u128(108928335532182294007310260467058477094);
+    pub const INSERTED: bevy_diagnostic::DiagnosticId =
+        bevy_diagnostic::DiagnosticId::from_u128(39312734008079293333817750987866901922);
+
+    pub fn setup_system(mut diagnostics: bevy_ecs::system::ResMut<bevy_diagnostic::Diagnostics>) {
+        diagnostics.add(bevy_diagnostic::Diagnostic::new(
+            Self::INITIALIZED,
+            "resources_initialized",
+            1,
+        ));
+        diagnostics.add(bevy_diagnostic::Diagnostic::new(
+            Self::INSERTED,
+            "resources_inserted",
+            1,
+        ));
+    }
+
+    pub fn diagnostic_system(
+        mut diagnostics: bevy_ecs::system::ResMut<bevy_diagnostic::Diagnostics>,
+        counts: bevy_ecs::system::Res<ResourceTupleCounts>,
+    ) {
+        diagnostics.add_measurement(Self::INITIALIZED, || counts.initialized as f64);
+        diagnostics.add_measurement(Self::INSERTED, || counts.inserted as f64);
+    }
+}
+
+/// Extends [`Commands`] with `init_resources`.
+pub trait CommandsInitResources<'w, 's> {
+    /// Pushes a [`Command`] to the queue for inserting a [`Resource`] in the [`World`] with an inferred value.
+    ///
+    /// The inferred value is determined by the [`FromWorld`] trait of the resource.
+    /// When the command is applied,
+    /// if the resource already exists, nothing happens.
+    ///
+    /// See [`World::init_resource`] for more details.
+    ///
+    /// # Ordering
+    ///
+    /// All of the resources are initialized by a single [`Command`],
+    /// so commands queued before this see none of them and commands queued after see all of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct PlayerScoreboard {
+    /// #     current_score: u32,
+    /// #     high_score: u32,
+    /// # }
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct EnemyScoreboard {
+    /// #     current_score: u32,
+    /// #     high_score: u32,
+    /// # }
+    /// #
+    /// # fn initialise_scoreboards(mut commands: Commands) {
+    /// commands.init_resources::<(PlayerScoreboard, EnemyScoreboard)>();
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(initialise_scoreboards);
+    /// ```
+    fn init_resources<R: InitResources>(&mut self) -> ResourceGroupCommands<'w, 's, '_, R>;
+}
+
+impl<'w, 's> CommandsInitResources<'w, 's> for Commands<'w, 's> {
+    fn init_resources<R: InitResources>(&mut self) -> ResourceGroupCommands<'w, 's, '_, R> {
+        let after = AfterInit::<R>::default();
+        let queued = Arc::clone(&after);
+        self.add(move |world: &mut World| {
+            let ids = world.init_resources::<R>();
+            for f in queued.lock().unwrap().drain(..) {
+                f(&ids);
+            }
+        });
+        ResourceGroupCommands {
+            commands: self,
+            after,
+        }
+    }
+}
+
+/// A handle for queuing follow-up commands for the resources from `init_resources` on [`Commands`].
+///
+/// Like [`EntityCommands`](bevy_ecs::system::EntityCommands), every operation is deferred until the commands are applied.
+pub struct ResourceGroupCommands<'w, 's, 'a, R: InitResources> {
+    commands: &'a mut Commands<'w, 's>,
+    after: AfterInit<R>,
+}
+
+/// Callbacks the queued init command hands the ids to once it has run.
+type AfterInit<R> = Arc<Mutex<Vec<Box<dyn FnOnce(&<R as InitResources>::IDS) + Send>>>>;
+
+impl<'w, 's, R: InitResources> ResourceGroupCommands<'w, 's, '_, R> {
+    /// Runs `f` with the [`ComponentId`]s of the resources right after the queued command initializes them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use std::sync::mpsc;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands
+    ///     .init_resources::<(Score, Lives)>()
+    ///     .after(move |ids| sender.send(ids).unwrap());
+    /// queue.apply(&mut world);
+    ///
+    /// let [score, _] = receiver.recv().unwrap();
+    /// assert_eq!(world.components().resource_id::<Score>(), Some(score));
+    /// ```
+    pub fn after(&mut self, f: impl FnOnce(R::IDS) + Send + 'static) -> &mut Self
+    where
+        R::IDS: Clone,
+    {
+        self.after
+            .lock()
+            .unwrap()
+            .push(Box::new(move |ids: &R::IDS| f(ids.clone())));
+        self
+    }
+
+    /// Returns the underlying [`Commands`].
+    pub fn commands(&mut self) -> &mut Commands<'w, 's> {
+        self.commands
+    }
+}
+
+impl<R: InitResources> fmt::Debug for ResourceGroupCommands<'_, '_, '_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceGroupCommands")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`Command`] for `init_resources`.
+pub struct InitResourcesCommand<R: InitResources> {
+    _phantom: PhantomData<R>,
+}
+
+impl<R: InitResources> fmt::Debug for InitResourcesCommand<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitResourcesCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: InitResources> Command for InitResourcesCommand<R> {
+    fn write(self, world: &mut World) {
+        world.init_resources::<R>();
+    }
+}
+
+impl<R: InitResources> Default for InitResourcesCommand<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: InitResources> InitResourcesCommand<R> {
+    /// Creates a [`Command`] which will insert a default created [`Resource`] into the [`World`]
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData::<R>,
+        }
+    }
+}
+
+/// Extends [`World`] with `insert_resources`.
+pub trait WorldInsertResources {
+    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> R::IDS;
+
+    fn insert_resources_dedup<R: InsertResources>(&mut self, resources: R) -> R::IDS;
+
+    fn insert_resources_then<R: InsertResources, F, T>(&mut self, resources: R, f: F) -> T
+    where
+        F: FnOnce(&mut World, R::IDS) -> T;
+}
+
+impl WorldInsertResources for World {
+    /// Inserts new resources with the given values and returns the [`ComponentId`]s for them.
+    ///
+    /// Resources are "unique" data of a given type.
+    /// If you insert a resource of a type that already exists,
+    /// you will overwrite any existing data.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics before inserting anything if the same resource type appears more than once in the tuple,
+    /// since the later value would silently overwrite the earlier one.
+    /// Release builds skip this check.
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(1), Score(2)));
+    /// ```
+    #[track_caller]
+    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+
+    /// Inserts new resources like [`insert_resources`](WorldInsertResources::insert_resources),
+    /// but logs duplicates instead of panicking on them.
+    ///
+    /// In debug builds, each position that repeats an earlier resource type is logged as a warning,
+    /// along with the position it overwrites.
+    /// The resources are still inserted in order, so the last value wins.
+    /// Release builds skip the check entirely.
+    ///
+    /// Only the resources within a single tuple are compared, so duplicates across the two halves of a
+    /// [`Chain`] aren't logged.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// // Warns that `Score` at position 2 overwrites the one at position 0.
+    /// world.insert_resources_dedup((Score(1), Lives(3), Score(2)));
+    /// assert_eq!(world.resource::<Score>().0, 2);
+    /// ```
+    fn insert_resources_dedup<R: InsertResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_resources_dedup(self);
+        end_batch(self, batch);
+        ids
+    }
+
+    /// Inserts new resources, then calls `f` with the [`World`] and the [`ComponentId`]s for them.
+    ///
+    /// `f` runs synchronously right after the resources are inserted, before this returns,
+    /// so it can configure them further in the same expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let score_id = world.insert_resources_then((Score(0), Lives(3)), |world, [score_id, _]| {
+    ///     world.resource_mut::<Lives>().0 += 1;
+    ///     score_id
+    /// });
+    ///
+    /// assert_eq!(world.components().resource_id::<Score>(), Some(score_id));
+    /// assert_eq!(world.resource::<Lives>().0, 4);
+    /// ```
+    #[track_caller]
+    fn insert_resources_then<R: InsertResources, F, T>(&mut self, resources: R, f: F) -> T
+    where
+        F: FnOnce(&mut World, R::IDS) -> T,
+    {
+        let ids = self.insert_resources(resources);
+        f(self, ids)
+    }
+}
+
+/// Error returned by `try_insert_resources` when some of the resources already exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsertError {
+    /// The type names of the resources that were already present.
+    pub type_names: Vec<&'static str>,
+}
+
+impl fmt::Display for InsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "resources already exist: {}", self.type_names.join(", "))
+    }
+}
+
+impl std::error::Error for InsertError {}
+
+/// Extends [`World`] with `try_insert_resources`.
+pub trait WorldTryInsertResources {
+    /// Inserts new resources with the given values, unless any of them already exist.
+    ///
+    /// All resources are checked before anything is inserted,
+    /// so on error the [`World`] is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InsertError`] naming every resource that was already present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Wind(1.0));
+    ///
+    /// let error = world.try_insert_resources((Gravity(9.8), Wind(2.0))).unwrap_err();
+    /// assert_eq!(error.type_names, [std::any::type_name::<Wind>()]);
+    /// assert!(!world.contains_resource::<Gravity>());
+    /// assert_eq!(world.resource::<Wind>().0, 1.0);
+    /// ```
+    fn try_insert_resources<R: InsertResources>(
+        &mut self,
+        resources: R,
+    ) -> Result<R::IDS, InsertError>;
+}
+
+impl WorldTryInsertResources for World {
+    #[track_caller]
+    fn try_insert_resources<R: InsertResources>(
+        &mut self,
+        resources: R,
+    ) -> Result<R::IDS, InsertError> {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.try_insert_resources(self)?;
+        end_batch(self, batch);
+        Ok(ids)
+    }
+}
+
+/// Extends [`World`] with `insert_resources_with_policy`.
+pub trait WorldInsertResourcesWithPolicy {
+    /// Inserts new resources, handling the ones that already exist according to `policy`,
+    /// and reports what happened to each of them.
+    ///
+    /// The outcomes are returned as a tuple, with an [`InsertOutcome`] for each resource.
+    /// Overwritten resources hand back their previous value, and skipped ones hand back the value that wasn't inserted.
+    /// `insert_resources`, `insert_resources_if_absent` and `try_insert_resources` remain the lighter options
+    /// when the values aren't needed back.
+    ///
+    /// # Errors
+    ///
+    /// With [`OverwritePolicy::Error`], returns an [`InsertError`] naming every resource that was already present,
+    /// and inserts nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, InsertOutcome, OverwritePolicy};
+    /// #
+    /// # #[derive(Resource, Debug, PartialEq)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Debug, PartialEq)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// let (score, lives) = world
+    ///     .insert_resources_with_policy((Score(0), Lives(5)), OverwritePolicy::Overwrite)
+    ///     .unwrap();
+    /// assert_eq!(score, InsertOutcome::Inserted);
+    /// assert_eq!(lives, InsertOutcome::Overwritten(Lives(3)));
+    ///
+    /// let (_, lives) = world
+    ///     .insert_resources_with_policy((Score(1), Lives(1)), OverwritePolicy::Skip)
+    ///     .unwrap();
+    /// assert_eq!(lives, InsertOutcome::Skipped(Lives(1)));
+    /// assert_eq!(world.resource::<Lives>().0, 5);
+    ///
+    /// let error = world
+    ///     .insert_resources_with_policy((Score(2),), OverwritePolicy::Error)
+    ///     .unwrap_err();
+    /// assert_eq!(error.type_names, [std::any::type_name::<Score>()]);
+    /// ```
+    fn insert_resources_with_policy<R: InsertResourcesWithPolicy>(
+        &mut self,
+        resources: R,
+        policy: OverwritePolicy,
+    ) -> Result<R::Outcomes, InsertError>;
+}
+
+impl WorldInsertResourcesWithPolicy for World {
+    #[track_caller]
+    fn insert_resources_with_policy<R: InsertResourcesWithPolicy>(
+        &mut self,
+        resources: R,
+        policy: OverwritePolicy,
+    ) -> Result<R::Outcomes, InsertError> {
+        let kind = match policy {
+            OverwritePolicy::Skip => BatchKind::InsertIfAbsent,
+            OverwritePolicy::Overwrite | OverwritePolicy::Error => BatchKind::Insert,
+        };
+        let batch = begin_batch(self, kind, R::resource_types);
+        let outcomes = resources.insert_resources_with_policy(self, policy)?;
+        end_batch(self, batch);
+        Ok(outcomes)
+    }
+}
+
+/// Extends [`App`] with `insert_resources` and `insert_resources_checked`.
+pub trait AppInsertResources {
+    /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
+    ///
+    /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
+    /// before using them. This happens with [`insert_resources`](Self::insert_resources).
+    ///
+    /// See `init_resources` for [`Resource`]s that implement [`Default`] or [`FromWorld`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// #[derive(Resource)]
+    /// struct MyCounter {
+    ///     counter: usize,
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct MyValue {
+    ///     value: f32,
+    /// }
+    ///
+    /// App::new()
+    ///    .insert_resources((MyCounter { counter: 0 }, MyValue { value: 1.0 }));
+    /// ```
+    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self;
+
+    /// Inserts resources like `insert_resources`,
+    /// but logs a warning for every resource that already exists and is therefore overwritten.
+    ///
+    /// This helps track down plugins that insert the same resources, where the last one silently wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct AudioConfig(f32);
+    /// #
+    /// let mut app = App::new();
+    /// app.insert_resources_checked((AudioConfig(1.0),))
+    ///     // Logs that `AudioConfig` is overwritten.
+    ///     .insert_resources_checked((AudioConfig(0.5),));
+    ///
+    /// assert_eq!(app.world.resource::<AudioConfig>().0, 0.5);
+    /// ```
+    fn insert_resources_checked<R: InsertResources>(&mut self, resources: R) -> &mut Self;
+
+    /// Inserts the resources returned by `f`, overwriting any previous values of the same types.
+    ///
+    /// On [`App`], `f` is called right away.
+    /// See [`CommandsInsertResources::insert_resources_with_fn`] for deferring the construction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Terrain(Vec<f32>);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Seed(u64);
+    /// #
+    /// let mut app = App::new();
+    /// app.insert_resources_with_fn(|| (Terrain(vec![0.0; 1024]), Seed(42)));
+    ///
+    /// assert_eq!(app.world.resource::<Terrain>().0.len(), 1024);
+    /// ```
+    fn insert_resources_with_fn<R: InsertResources, F: FnOnce() -> R>(&mut self, f: F)
+        -> &mut Self;
+}
+
+impl AppInsertResources for App {
+    #[track_caller]
+    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self {
+        self.world.insert_resources(resources);
+        self
+    }
+
+    #[track_caller]
+    fn insert_resources_checked<R: InsertResources>(&mut self, resources: R) -> &mut Self {
+        let mut type_names = Vec::new();
+        R::existing_type_names(&self.world, &mut type_names);
+        for type_name in type_names {
+            warn!("resource `{type_name}` already exists, so it will be overwritten");
+        }
+
+        self.world.insert_resources(resources);
+        self
+    }
+
+    #[track_caller]
+    fn insert_resources_with_fn<R: InsertResources, F: FnOnce() -> R>(
+        &mut self,
+        f: F,
+    ) -> &mut Self {
+        self.world.insert_resources(f());
+        self
+    }
+}
+
+/// Extends [`App`] with `insert_resources_on_enter`.
+pub trait AppInsertResourcesOnEnter {
+    /// Inserts [`Resource`]s every time the [`App`] enters the given state,
+    /// overwriting any [`Resource`]s of the same type.
+    ///
+    /// The [`Resource`]s are moved into a system that runs in the [`OnEnter`] schedule of the state.
+    /// Since the state can be entered more than once, a clone of the [`Resource`]s is inserted each time,
+    /// which is why they must implement [`Clone`].
+    /// To only insert them the first time, use `init_resources` with [`Default`] or [`FromWorld`] instead,
+    /// which doesn't overwrite existing [`Resource`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Lives(u32);
+    /// #
+    /// #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Menu,
+    ///     Playing,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_state::<GameState>()
+    ///     .insert_resources_on_enter(GameState::Playing, (Score(0), Lives(3)));
+    ///
+    /// app.update();
+    /// assert!(!app.world.contains_resource::<Score>());
+    ///
+    /// app.world.insert_resource(NextState(Some(GameState::Playing)));
+    /// app.update();
+    /// assert_eq!(app.world.resource::<Lives>().0, 3);
+    /// ```
+    fn insert_resources_on_enter<S: States, R: InsertResources + Clone>(
+        &mut self,
+        state: S,
+        resources: R,
+    ) -> &mut Self;
+}
+
+impl AppInsertResourcesOnEnter for App {
+    fn insert_resources_on_enter<S: States, R: InsertResources + Clone>(
+        &mut self,
+        state: S,
+        resources: R,
+    ) -> &mut Self {
+        self.add_system(
+            (move |world: &mut World| {
+                world.insert_resources(resources.clone());
+            })
+            .in_schedule(OnEnter(state)),
+        )
+    }
+}
+
+/// Extends [`Commands`] with `insert_resources`.
+pub trait CommandsInsertResources {
+    /// Pushes a [`Command`] to the queue for inserting a [`Resource`] in the [`World`] with a specific value.
+    ///
+    /// This will overwrite any previous value of the same resource type.
+    ///
+    /// See [`World::insert_resources`] for more details.
+    ///
+    /// # Ordering
+    ///
+    /// All of the resources are inserted by a single [`Command`],
+    /// so commands queued before this see none of them and commands queued after see all of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct PlayerScoreboard(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct EnemyScoreboard(u32);
+    /// #
+    /// # fn system(mut commands: Commands) {
+    /// commands.insert_resources((
+    ///     PlayerScoreboard(0),
+    ///     EnemyScoreboard(0),
+    /// ));
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn insert_resources<R: InsertResources>(&mut self, resources: R);
+
+    /// Pushes a [`Command`] to the queue for inserting the resources returned by `f`.
+    ///
+    /// `f` isn't called until the command is applied,
+    /// so expensive resources aren't constructed while the system runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Terrain(Vec<f32>);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Seed(u64);
+    /// #
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    ///
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands.insert_resources_with_fn(|| (Terrain(vec![0.0; 1024]), Seed(42)));
+    /// assert!(!world.contains_resource::<Terrain>());
+    ///
+    /// queue.apply(&mut world);
+    /// assert_eq!(world.resource::<Seed>().0, 42);
+    /// ```
+    fn insert_resources_with_fn<R: InsertResources, F>(&mut self, f: F)
+    where
+        F: FnOnce() -> R + Send + 'static;
+
+    /// Pushes a [`Command`] to the queue for inserting the resources that don't exist yet.
+    ///
+    /// Whether each resource exists is checked when the command is applied, not when it's queued,
+    /// so a resource inserted by an earlier command is kept.
+    ///
+    /// See [`World::insert_resources_if_absent`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    ///
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands.insert_resource(Score(10));
+    /// commands.insert_resources_if_absent((Score(0), Lives(3)));
+    /// queue.apply(&mut world);
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert_eq!(world.resource::<Lives>().0, 3);
+    /// ```
+    fn insert_resources_if_absent<R: InsertResources>(&mut self, resources: R);
+}
+
+impl CommandsInsertResources for Commands<'_, '_> {
+    fn insert_resources<R: InsertResources>(&mut self, resources: R) {
+        self.add(InsertResourcesCommand { resources });
+    }
+
+    fn insert_resources_with_fn<R: InsertResources, F>(&mut self, f: F)
+    where
+        F: FnOnce() -> R + Send + 'static,
+    {
+        self.add(move |world: &mut World| {
+            world.insert_resources(f());
+        });
+    }
+
+    fn insert_resources_if_absent<R: InsertResources>(&mut self, resources: R) {
+        self.add(InsertResourcesIfAbsentCommand { resources });
+    }
+}
+
+/// [`Command`] for `insert_resources`.
+///
+/// The [`Debug`](fmt::Debug) implementation prints the types of the resources rather than their values,
+/// so it doesn't require the resources to implement [`Debug`](fmt::Debug).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::InsertResourcesCommand;
+/// #
+/// # #[derive(Resource)]
+/// # struct Score(u32);
+/// #
+/// let command = InsertResourcesCommand { resources: (Score(0),) };
+/// assert!(format!("{command:?}").contains("Score"));
+/// ```
+pub struct InsertResourcesCommand<R: InsertResources> {
+    pub resources: R,
+}
+
+impl<R: InsertResources> fmt::Debug for InsertResourcesCommand<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InsertResourcesCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: InsertResources> Command for InsertResourcesCommand<R> {
+    fn write(self, world: &mut World) {
+        world.insert_resources(self.resources);
+    }
+}
+
+impl<R: InsertResources> InsertResourcesCommand<R> {
+    /// Creates a [`Command`] that inserts the resources and then passes their [`ComponentId`]s to `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::InsertResourcesCommand;
+    /// # use std::sync::mpsc;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands.add(InsertResourcesCommand::with_callback(
+    ///     (Score(0), Lives(3)),
+    ///     move |ids| sender.send(ids).unwrap(),
+    /// ));
+    /// queue.apply(&mut world);
+    ///
+    /// let [score, lives] = receiver.recv().unwrap();
+    /// assert_eq!(world.components().resource_id::<Score>(), Some(score));
+    /// assert_eq!(world.components().resource_id::<Lives>(), Some(lives));
+    /// ```
+    pub fn with_callback<F>(resources: R, f: F) -> InsertResourcesWithCallbackCommand<R, F>
+    where
+        F: FnOnce(R::IDS) + Send + 'static,
+    {
+        InsertResourcesWithCallbackCommand { resources, f }
+    }
+}
+
+/// [`Command`] for `insert_resources` that passes the [`ComponentId`]s to a callback.
+///
+/// Created by [`InsertResourcesCommand::with_callback`].
+pub struct InsertResourcesWithCallbackCommand<R: InsertResources, F> {
+    pub resources: R,
+    pub f: F,
+}
+
+impl<R: InsertResources, F> fmt::Debug for InsertResourcesWithCallbackCommand<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InsertResourcesWithCallbackCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R, F> Command for InsertResourcesWithCallbackCommand<R, F>
+where
+    R: InsertResources,
+    F: FnOnce(R::IDS) + Send + 'static,
+{
+    fn write(self, world: &mut World) {
+        let ids = world.insert_resources(self.resources);
+        (self.f)(ids);
+    }
+}
+
+/// [`Command`] for `insert_resources_if_absent`.
+pub struct InsertResourcesIfAbsentCommand<R: InsertResources> {
+    pub resources: R,
+}
+
+impl<R: InsertResources> fmt::Debug for InsertResourcesIfAbsentCommand<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InsertResourcesIfAbsentCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: InsertResources> Command for InsertResourcesIfAbsentCommand<R> {
+    fn write(self, world: &mut World) {
+        world.insert_resources_if_absent(self.resources);
+    }
+}
+
+/// Something that resources can be inserted into or initialized in,
+/// so setup code can be written once for [`World`], [`App`] and [`Commands`].
+///
+/// Use [`SinkResources`] to insert or initialize groups of resources through a sink.
+pub trait ResourceSink {
+    /// Inserts a single resource, overwriting any existing value.
+    fn insert_one<R: Resource>(&mut self, resource: R);
+
+    /// Initializes a single resource with [`FromWorld`], if it doesn't exist yet.
+    fn init_one<R: Resource + FromWorld>(&mut self);
+
+    /// Inserts a group of resources at once, overwriting any existing values.
+    fn insert_group<R: InsertResources>(&mut self, resources: R);
+
+    /// Initializes a group of resources at once, skipping the ones that already exist.
+    fn init_group<R: InitResources>(&mut self);
+}
+
+impl ResourceSink for World {
+    fn insert_one<R: Resource>(&mut self, resource: R) {
+        self.insert_resources((resource,));
+    }
+
+    fn init_one<R: Resource + FromWorld>(&mut self) {
+        self.init_resources::<(R,)>();
+    }
+
+    #[track_caller]
+    fn insert_group<R: InsertResources>(&mut self, resources: R) {
+        self.insert_resources(resources);
+    }
+
+    #[track_caller]
+    fn init_group<R: InitResources>(&mut self) {
+        self.init_resources::<R>();
+    }
+}
+
+impl ResourceSink for App {
+    fn insert_one<R: Resource>(&mut self, resource: R) {
+        self.world.insert_resources((resource,));
+    }
+
+    fn init_one<R: Resource + FromWorld>(&mut self) {
+        self.world.init_resources::<(R,)>();
+    }
+
+    #[track_caller]
+    fn insert_group<R: InsertResources>(&mut self, resources: R) {
+        self.world.insert_resources(resources);
+    }
+
+    #[track_caller]
+    fn init_group<R: InitResources>(&mut self) {
+        self.world.init_resources::<R>();
+    }
+}
+
+/// Resources are inserted and initialized when the commands are applied.
+///
+/// Each group is queued as a single command, like `insert_resources` and `init_resources` on [`Commands`].
+impl ResourceSink for Commands<'_, '_> {
+    fn insert_one<R: Resource>(&mut self, resource: R) {
+        self.add(InsertResourcesCommand {
+            resources: (resource,),
+        });
+    }
+
+    fn init_one<R: Resource + FromWorld>(&mut self) {
+        self.add(InitResourcesCommand::<(R,)>::new());
+    }
+
+    fn insert_group<R: InsertResources>(&mut self, resources: R) {
+        self.add(InsertResourcesCommand { resources });
+    }
+
+    fn init_group<R: InitResources>(&mut self) {
+        self.add(InitResourcesCommand::<R>::new());
+    }
+}
+
+/// Extends every [`ResourceSink`] with `sink_insert_resources` and `sink_init_resources`.
+///
+/// The methods have a `sink_` prefix so they don't clash with the `insert_resources` and `init_resources`
+/// methods of [`World`], [`App`] and [`Commands`].
+/// Unlike those, they don't return [`ComponentId`]s, since [`Commands`] apply them later.
+pub trait SinkResources: ResourceSink {
+    /// Inserts the resources into the sink.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::{prelude::*, ResourceSink, SinkResources};
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// fn setup(sink: &mut impl ResourceSink) {
+    ///     sink.sink_insert_resources((Score(0),))
+    ///         .sink_init_resources::<(Lives,)>();
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// setup(&mut world);
+    /// assert!(world.contains_resource::<Score>() && world.contains_resource::<Lives>());
+    ///
+    /// let mut app = App::new();
+    /// setup(&mut app);
+    /// assert!(app.world.contains_resource::<Score>() && app.world.contains_resource::<Lives>());
+    ///
+    /// let mut world = World::new();
+    /// let mut queue = CommandQueue::default();
+    /// setup(&mut Commands::new(&mut queue, &world));
+    /// queue.apply(&mut world);
+    /// assert!(world.contains_resource::<Score>() && world.contains_resource::<Lives>());
+    /// ```
+    fn sink_insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self;
+
+    /// Initializes the resources in the sink.
+    fn sink_init_resources<R: InitResources>(&mut self) -> &mut Self;
+}
+
+impl<T: ResourceSink + ?Sized> SinkResources for T {
+    #[track_caller]
+    fn sink_insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self {
+        self.insert_group(resources);
+        self
+    }
+
+    #[track_caller]
+    fn sink_init_resources<R: InitResources>(&mut self) -> &mut Self {
+        self.init_group::<R>();
+        self
+    }
+}
+
+/// Initializes and inserts groups of resources in one go.
+///
+/// The first argument is an [`App`], [`World`] or [`Commands`] (or anything else implementing [`ResourceSink`]),
+/// followed by an optional `init:` tuple of types to initialize and an optional `insert:` tuple of values to insert.
+/// The resources are initialized first and then inserted, just like calling `init_resources` and `insert_resources`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::resources;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Lives(u32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Gravity(f32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Wind(f32);
+/// #
+/// let mut app = App::new();
+/// resources!(app, init: (Score, Lives), insert: (Gravity(9.8), Wind(2.0)));
+/// assert_eq!(app.world.resource::<Gravity>().0, 9.8);
+///
+/// let mut world = World::new();
+/// resources!(world, insert: (Gravity(1.6),));
+/// assert!(!world.contains_resource::<Score>());
+///
+/// fn setup(mut commands: Commands) {
+///     resources!(commands, init: (Score, Lives));
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+///
+/// The resources can also be listed without tuples, separated from the target by semicolons,
+/// which avoids the trailing comma for a single resource:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::resources;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Lives(u32);
+/// #
+/// # #[derive(Resource)]
+/// # struct Gravity(f32);
+/// #
+/// let mut app = App::new();
+/// resources!(app; init Score, Lives; insert Gravity(9.8));
+/// assert_eq!(app.world.resource::<Gravity>().0, 9.8);
+/// ```
+#[macro_export]
+macro_rules! resources {
+    ($target:expr $(, init: $init:ty)? $(, insert: $insert:expr)? $(,)?) => {{
+        use $crate::SinkResources as _;
+        let target = &mut $target;
+        $(target.sink_init_resources::<$init>();)?
+        $(target.sink_insert_resources($insert);)?
+    }};
+    ($target:expr $(; init $($init:ty),+ $(,)?)? $(; insert $($insert:expr),+ $(,)?)? $(;)?) => {
+        $crate::resources!($target $(, init: ($($init,)+))? $(, insert: ($($insert,)+))?)
+    };
+}
+
+/// Inserts every resource yielded by the iterator into the [`World`].
+///
+/// Resources are unique per type, so every item overwrites the one before it
+/// and only the last item remains in the [`World`] afterwards.
+/// The earlier values are dropped as they are overwritten.
+/// This makes it useful for applying a stream of updates where the latest value wins,
+/// but not for storing a collection: for that, store the collection itself as one resource,
+/// or use [`insert_indexed_resources`] for a few items that should each be kept.
+///
+/// When the resources have distinct types known at compile time, prefer `insert_resources` with a tuple.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::*;
+/// #
+/// # #[derive(Resource)]
+/// # struct ServerTick(u32);
+/// #
+/// let mut world = World::new();
+/// insert_resources_from_iter(&mut world, [ServerTick(1), ServerTick(2), ServerTick(3)]);
+///
+/// assert_eq!(world.resource::<ServerTick>().0, 3);
+/// ```
+pub fn insert_resources_from_iter<R: Resource>(
+    world: &mut World,
+    iter: impl IntoIterator<Item = R>,
+) {
+    for resource in iter {
+        world.insert_resource(resource);
+    }
+}
+
+/// A resource wrapped with its position `I` in the iterator passed to [`insert_indexed_resources`],
+/// so each position is a distinct resource type.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Indexed<R, const I: usize>(pub R);
+
+/// The most items [`insert_indexed_resources`] accepts, one for each [`Indexed`] position.
+pub const MAX_INDEXED_RESOURCES: usize = 8;
+
+/// Inserts every resource yielded by the iterator into the [`World`] as an [`Indexed`] resource,
+/// so none of them overwrite each other.
+///
+/// The first item becomes `Indexed<R, 0>`, the second `Indexed<R, 1>`, and so on.
+/// Returns the number of resources inserted.
+///
+/// Only positions known at compile time can be read back, so this suits a handful of items,
+/// like one resource per local player. Prefer storing a `Vec` as one resource for longer lists.
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than [`MAX_INDEXED_RESOURCES`] items.
+/// The items before that are inserted already.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::*;
+/// #
+/// # #[derive(Resource)]
+/// # struct Gamepad(u32);
+/// #
+/// let mut world = World::new();
+/// let count = insert_indexed_resources(&mut world, [Gamepad(7), Gamepad(9)]);
+///
+/// assert_eq!(count, 2);
+/// assert_eq!(world.resource::<Indexed<Gamepad, 0>>().0 .0, 7);
+/// assert_eq!(world.resource::<Indexed<Gamepad, 1>>().0 .0, 9);
+/// ```
+#[track_caller]
+pub fn insert_indexed_resources<R: Resource>(
+    world: &mut World,
+    iter: impl IntoIterator<Item = R>,
+) -> usize {
+    let mut count = 0;
+    for resource in iter {
+        match count {
+            0 => world.insert_resource(Indexed::<R, 0>(resource)),
+            1 => world.insert_resource(Indexed::<R, 1>(resource)),
+            2 => world.insert_resource(Indexed::<R, 2>(resource)),
+            3 => world.insert_resource(Indexed::<R, 3>(resource)),
+            4 => world.insert_resource(Indexed::<R, 4>(resource)),
+            5 => world.insert_resource(Indexed::<R, 5>(resource)),
+            6 => world.insert_resource(Indexed::<R, 6>(resource)),
+            7 => world.insert_resource(Indexed::<R, 7>(resource)),
+            _ => panic!(
+                "`insert_indexed_resources` accepts at most {MAX_INDEXED_RESOURCES} resources of type `{}`",
+                std::any::type_name::<R>()
+            ),
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Extends [`World`] with `insert_resources_if_absent`.
+pub trait WorldInsertResourcesIfAbsent {
+    /// Inserts each resource only if it doesn't exist yet, and reports which ones were inserted.
+    ///
+    /// The returned array is in the same order as the tuple,
+    /// with `true` for resources that were inserted and `false` for ones that already existed.
+    /// The values for resources that already existed are dropped, so their destructors run.
+    /// If one of those destructors panics, the panic is propagated,
+    /// unless the `catch_drop_panics` feature is enabled, in which case it's caught and logged.
+    ///
+    /// Unlike `init_resources`, this takes concrete values, so the resources don't need to implement [`FromWorld`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Volume(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Brightness(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Volume(0.2));
+    ///
+    /// let inserted = world.insert_resources_if_absent((Volume(1.0), Brightness(1.0)));
+    ///
+    /// assert_eq!(inserted, [false, true]);
+    /// assert_eq!(world.resource::<Volume>().0, 0.2);
+    /// ```
+    fn insert_resources_if_absent<R: InsertResources>(&mut self, resources: R) -> R::Inserted;
+}
+
+impl WorldInsertResourcesIfAbsent for World {
+    #[track_caller]
+    fn insert_resources_if_absent<R: InsertResources>(&mut self, resources: R) -> R::Inserted {
+        let batch = begin_batch(self, BatchKind::InsertIfAbsent, R::resource_types);
+        let inserted = resources.insert_resources_if_absent(self);
+        end_batch(self, batch);
+        inserted
+    }
+}
+
+/// Extends [`World`] with `contains_resources`, `contains_all_resources`, `contains_any_resources` and `present_count`.
+pub trait WorldContainsResources {
+    /// Returns whether each of the resources exists, in the same order as the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// assert_eq!(world.contains_resources::<(Score, Lives)>(), [false, true]);
+    /// ```
+    fn contains_resources<R: ContainsResources>(&self) -> R::Contains;
+
+    /// Returns `true` if all of the resources exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    /// assert!(!world.contains_all_resources::<(Score, Lives)>());
+    ///
+    /// world.insert_resource(Score(0));
+    /// assert!(world.contains_all_resources::<(Score, Lives)>());
+    /// ```
+    fn contains_all_resources<R: ContainsResources>(&self) -> bool;
+
+    /// Returns `true` if any of the resources exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// assert!(!world.contains_any_resources::<(Score, Lives)>());
+    ///
+    /// world.insert_resource(Lives(3));
+    /// assert!(world.contains_any_resources::<(Score, Lives)>());
+    /// ```
+    fn contains_any_resources<R: ContainsResources>(&self) -> bool;
+
+    /// Returns how many of the resources exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Level(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// assert_eq!(world.present_count::<(Score, Lives, Level)>(), 1);
+    /// ```
+    fn present_count<R: ContainsResources>(&self) -> usize;
+}
+
+impl WorldContainsResources for World {
+    fn contains_resources<R: ContainsResources>(&self) -> R::Contains {
+        R::contains_resources(self)
+    }
+
+    fn contains_all_resources<R: ContainsResources>(&self) -> bool {
+        self.contains_resources::<R>().as_ref().iter().all(|b| *b)
+    }
+
+    fn contains_any_resources<R: ContainsResources>(&self) -> bool {
+        self.contains_resources::<R>().as_ref().iter().any(|b| *b)
+    }
+
+    fn present_count<R: ContainsResources>(&self) -> usize {
+        self.contains_resources::<R>()
+            .as_ref()
+            .iter()
+            .filter(|b| **b)
+            .count()
+    }
+}
+
+/// Extends [`World`] with `for_each_resource` and `for_each_resource_mut`.
+pub trait WorldForEachResource {
+    /// Calls the visitor with each of the resources that exist, in order.
+    ///
+    /// The visitor decides which trait the resources are used through:
+    /// implement [`VisitResource`] for every `R` bounded by that trait,
+    /// and the group can only be visited if all of its resources implement it.
+    /// Each resource is visited with a direct call on its concrete type, without dynamic dispatch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, VisitResource};
+    /// #
+    /// trait Persist {
+    ///     fn save(&self) -> String;
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// impl Persist for Score {
+    ///     fn save(&self) -> String {
+    ///         format!("score={}", self.0)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Lives(u32);
+    ///
+    /// impl Persist for Lives {
+    ///     fn save(&self) -> String {
+    ///         format!("lives={}", self.0)
+    ///     }
+    /// }
+    ///
+    /// struct Save(Vec<String>);
+    ///
+    /// impl<R: Persist> VisitResource<R> for Save {
+    ///     fn visit(&mut self, resource: &R) {
+    ///         self.0.push(resource.save());
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    ///
+    /// let mut save = Save(Vec::new());
+    /// world.for_each_resource::<(Score, Lives), _>(&mut save);
+    /// assert_eq!(save.0, ["lives=3"]);
+    /// ```
+    fn for_each_resource<R: VisitResources<V>, V>(&self, visitor: &mut V);
+
+    /// Calls the visitor with each of the resources that exist mutably, in order.
+    ///
+    /// Like `for_each_resource`, but the visitor implements [`VisitResourceMut`] and gets a [`Mut`],
+    /// so resources are only marked as changed if the visitor changes them.
+    fn for_each_resource_mut<R: VisitResourcesMut<V>, V>(&mut self, visitor: &mut V);
+}
+
+impl WorldForEachResource for World {
+    fn for_each_resource<R: VisitResources<V>, V>(&self, visitor: &mut V) {
+        R::visit_resources(self, visitor);
+    }
+
+    fn for_each_resource_mut<R: VisitResourcesMut<V>, V>(&mut self, visitor: &mut V) {
+        R::visit_resources_mut(self, visitor);
+    }
+}
+
+/// Extends [`World`] with `clone_resources_into`.
+pub trait WorldCloneResources {
+    /// Clones the resources from this [`World`] into `target`, overwriting any existing values there.
+    ///
+    /// Resources that don't exist in this [`World`] are skipped.
+    ///
+    /// This is a direct in-memory copy using [`Clone`], so unlike reflection-based copying,
+    /// the worlds don't need to share a type registry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut scratch = World::new();
+    /// scratch.insert_resource(Score(10));
+    ///
+    /// let mut world = World::new();
+    /// scratch.clone_resources_into::<(Score, Lives)>(&mut world);
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert!(!world.contains_resource::<Lives>());
+    /// ```
+    fn clone_resources_into<R: CloneResources>(&self, target: &mut World);
+}
+
+impl WorldCloneResources for World {
+    fn clone_resources_into<R: CloneResources>(&self, target: &mut World) {
+        let batch = begin_batch(target, BatchKind::Insert, |types| {
+            cloned_resource_types::<R>(self, types);
+        });
+        R::clone_resources_into(self, target);
+        end_batch(target, batch);
+    }
+}
+
+/// Extends [`World`] with `snapshot_resources` and `restore_resources`.
+pub trait WorldSnapshotResources {
+    /// Clones the resources out of the [`World`] as a snapshot, if all of them exist.
+    ///
+    /// If any of the resources don't exist, `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Cursor(u32, u32);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Selection(Vec<u32>);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Cursor(4, 2), Selection(vec![1, 2])));
+    ///
+    /// let mut undo = Vec::new();
+    /// undo.push(world.snapshot_resources::<(Cursor, Selection)>().unwrap());
+    ///
+    /// world.resource_mut::<Cursor>().0 = 8;
+    /// world.resource_mut::<Selection>().0.clear();
+    ///
+    /// world.restore_resources(undo.pop().unwrap());
+    /// assert_eq!((world.resource::<Cursor>().0, world.resource::<Cursor>().1), (4, 2));
+    /// assert_eq!(world.resource::<Selection>().0, [1, 2]);
+    /// ```
+    fn snapshot_resources<R: CloneResources>(&self) -> Option<R>;
+
+    /// Restores a snapshot from `snapshot_resources`, overwriting the current values of the resources.
+    ///
+    /// This is the same as `insert_resources`.
+    fn restore_resources<R: InsertResources>(&mut self, snapshot: R);
+}
+
+impl WorldSnapshotResources for World {
+    fn snapshot_resources<R: CloneResources>(&self) -> Option<R> {
+        R::snapshot_resources(self)
+    }
+
+    #[track_caller]
+    fn restore_resources<R: InsertResources>(&mut self, snapshot: R) {
+        self.insert_resources(snapshot);
+    }
+}
+
+/// Extends [`World`] with `extend_resources_overwriting` and `extend_resources_if_absent`.
+pub trait WorldExtendResources {
+    /// Clones the resources from `other` into this [`World`], overwriting any existing values here.
+    ///
+    /// Resources that don't exist in `other` are skipped.
+    /// This is the same as `clone_resources_into`, called from the receiving [`World`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut other = World::new();
+    /// other.insert_resource(Score(10));
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Lives(3)));
+    /// world.extend_resources_overwriting::<(Score, Lives)>(&other);
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert_eq!(world.resource::<Lives>().0, 3);
+    /// ```
+    fn extend_resources_overwriting<R: CloneResources>(&mut self, other: &World);
+
+    /// Clones the resources from `other` into this [`World`], keeping any existing values here.
+    ///
+    /// Resources that don't exist in `other` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut other = World::new();
+    /// other.insert_resources((Score(10), Lives(1)));
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(0));
+    /// world.extend_resources_if_absent::<(Score, Lives)>(&other);
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 0);
+    /// assert_eq!(world.resource::<Lives>().0, 1);
+    /// ```
+    fn extend_resources_if_absent<R: CloneResources>(&mut self, other: &World);
+}
+
+impl WorldExtendResources for World {
+    fn extend_resources_overwriting<R: CloneResources>(&mut self, other: &World) {
+        other.clone_resources_into::<R>(self);
+    }
+
+    fn extend_resources_if_absent<R: CloneResources>(&mut self, other: &World) {
+        let batch = begin_batch(self, BatchKind::InsertIfAbsent, |types| {
+            cloned_resource_types::<R>(other, types);
+        });
+        R::clone_resources_into_if_absent(other, self);
+        end_batch(self, batch);
+    }
+}
+
+/// Appends the resources of `R` that exist in `source`, which are the ones cloning copies.
+fn cloned_resource_types<R: CloneResources>(
+    source: &World,
+    types: &mut Vec<(TypeId, &'static str)>,
+) {
+    R::resource_types(types);
+    types.retain(|&(type_id, _)| contains_resource_type(source, type_id));
+}
+
+/// Extends [`World`] with `move_resources`.
+pub trait WorldMoveResources {
+    /// Moves the resources from this [`World`] into `target`, overwriting any existing values there.
+    ///
+    /// Resources that don't exist in this [`World`] are skipped.
+    ///
+    /// Unlike `clone_resources_into`, this doesn't require [`Clone`].
+    /// All of the resources are taken out before any are inserted,
+    /// so `target` never sees part of the group while the rest are still in this [`World`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut scratch = World::new();
+    /// scratch.insert_resource(Score(10));
+    ///
+    /// let mut world = World::new();
+    /// scratch.move_resources::<(Score, Lives)>(&mut world);
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert!(!world.contains_resource::<Lives>());
+    /// assert!(!scratch.contains_resource::<Score>());
+    /// ```
+    fn move_resources<R: RemoveResources>(&mut self, target: &mut World)
+    where
+        R::Partial: InsertOptionalResources;
+}
+
+impl WorldMoveResources for World {
+    fn move_resources<R: RemoveResources>(&mut self, target: &mut World)
+    where
+        R::Partial: InsertOptionalResources,
+    {
+        target.insert_optional_resources(R::try_take_resources(self));
+    }
+}
+
+/// Extends [`World`] with `resource_change_ticks`, `resources_changed` and `any_resources_changed`.
+pub trait WorldResourceChangeTicks {
+    /// Returns the [`ComponentTicks`] of each resource, recording when it was added and last changed.
+    ///
+    /// Resources that don't exist have `None` in place of their ticks,
+    /// including ones that have never been inserted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Level(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Lives(3)));
+    /// world.clear_trackers();
+    ///
+    /// world.resource_mut::<Score>().0 += 10;
+    ///
+    /// let (score, lives, level) = world.resource_change_ticks::<(Score, Lives, Level)>();
+    /// let (last_change_tick, change_tick) = (world.last_change_tick(), world.read_change_tick());
+    ///
+    /// assert!(score.unwrap().is_changed(last_change_tick, change_tick));
+    /// assert!(!lives.unwrap().is_changed(last_change_tick, change_tick));
+    /// assert!(level.is_none());
+    /// ```
+    fn resource_change_ticks<R: ResourceChangeTicks>(&self) -> R::Ticks;
+
+    /// Returns whether each resource was added or changed since the last change tick,
+    /// like [`DetectChanges::is_changed`](bevy_ecs::change_detection::DetectChanges::is_changed).
+    ///
+    /// Inside an exclusive system, this is since the last time the system ran.
+    /// Resources that don't exist are `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Level(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Lives(3)));
+    /// world.clear_trackers();
+    ///
+    /// world.resource_mut::<Lives>().0 -= 1;
+    /// assert_eq!(world.resources_changed::<(Score, Lives, Level)>(), [false, true, false]);
+    /// ```
+    fn resources_changed<R: ResourceChangeTicks>(&self) -> R::Changed;
+
+    /// Returns `true` if any of the resources were added or changed since the last change tick.
+    ///
+    /// Inside an exclusive system, this is since the last time the system ran.
+    /// Resources that don't exist are treated as unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Lives(3)));
+    /// world.clear_trackers();
+    /// assert!(!world.any_resources_changed::<(Score, Lives)>());
+    ///
+    /// world.insert_resource(Lives(2));
+    /// assert!(world.any_resources_changed::<(Score, Lives)>());
+    /// ```
+    fn any_resources_changed<R: ResourceChangeTicks>(&self) -> bool;
+
+    /// Returns whether each resource was added after the `since` tick.
+    ///
+    /// Unlike [`resources_changed`](WorldResourceChangeTicks::resources_changed),
+    /// resources that were only changed, or overwritten with `insert_resource`, don't count.
+    /// Resources that don't exist report `false`.
+    ///
+    /// Resources added in the same tick as `since` don't count either,
+    /// so take the tick with [`World::increment_change_tick`], which returns the tick before incrementing it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Level(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Lives(3)));
+    ///
+    /// let since = world.increment_change_tick();
+    /// world.resource_mut::<Score>().0 += 10;
+    /// world.insert_resource(Level(1));
+    ///
+    /// assert_eq!(world.resources_added_since::<(Score, Lives, Level)>(since), [false, false, true]);
+    /// ```
+    fn resources_added_since<R: ResourceChangeTicks>(&self, since: u32) -> R::Changed;
+}
+
+impl WorldResourceChangeTicks for World {
+    fn resource_change_ticks<R: ResourceChangeTicks>(&self) -> R::Ticks {
+        R::resource_change_ticks(self)
+    }
+
+    fn resources_changed<R: ResourceChangeTicks>(&self) -> R::Changed {
+        R::resources_changed(self, self.last_change_tick(), self.read_change_tick())
+    }
+
+    fn any_resources_changed<R: ResourceChangeTicks>(&self) -> bool {
+        self.resources_changed::<R>().as_ref().contains(&true)
+    }
+
+    fn resources_added_since<R: ResourceChangeTicks>(&self, since: u32) -> R::Changed {
+        R::resources_added_since(self, since, self.read_change_tick())
+    }
+}
+
+/// Extends [`App`] with `on_resources_changed`.
+pub trait AppOnResourcesChanged {
+    /// Adds an exclusive system that calls `f` whenever any of the resources are inserted or changed.
+    ///
+    /// The check runs once per update and covers every change since the previous run,
+    /// so `f` runs at most once per update no matter how many of the resources changed.
+    /// Resources that already exist count as changed on the first update.
+    /// Changes made by `f` itself don't trigger it again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Saves(u32);
+    /// #
+    /// let mut app = App::new();
+    /// app.insert_resources((Score(0), Lives(3), Saves(0)))
+    ///     .on_resources_changed::<(Score, Lives), _>(|world| world.resource_mut::<Saves>().0 += 1);
+    ///
+    /// app.update();
+    /// app.update();
+    /// assert_eq!(app.world.resource::<Saves>().0, 1);
+    ///
+    /// app.insert_resource(Lives(2));
+    /// app.update();
+    /// assert_eq!(app.world.resource::<Saves>().0, 2);
+    /// ```
+    fn on_resources_changed<R: ResourceChangeTicks, F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut(&mut World) + Send + Sync + 'static;
+}
+
+impl AppOnResourcesChanged for App {
+    fn on_resources_changed<R: ResourceChangeTicks, F>(&mut self, mut f: F) -> &mut Self
+    where
+        F: FnMut(&mut World) + Send + Sync + 'static,
+    {
+        self.add_system(move |world: &mut World| {
+            if world.any_resources_changed::<R>() {
+                f(world);
+            }
+        })
+    }
+}
+
+/// Extends [`World`] with `resource_ids`.
+pub trait WorldResourceIds {
+    /// Returns the [`ComponentId`]s of the resources, without initializing them.
+    ///
+    /// A resource only has a [`ComponentId`] once it has been initialized or inserted at least once,
+    /// otherwise `None` is returned in its place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// let score_id = world.init_resource::<Score>();
+    ///
+    /// assert_eq!(world.resource_ids::<(Score, Lives)>(), (Some(score_id), None));
+    /// ```
+    fn resource_ids<R: ResourceIds>(&self) -> R::IDS;
+}
+
+impl WorldResourceIds for World {
+    fn resource_ids<R: ResourceIds>(&self) -> R::IDS {
+        R::resource_ids(self)
+    }
+}
+
+/// Extends [`World`] with `for_each_resource_with_world`.
+pub trait WorldForEachResourceWithWorld {
+    /// Calls `f` with the [`World`] and the [`ComponentId`] of each resource that exists, in order.
+    ///
+    /// A typed [`Mut`] for the resource would alias the `&mut World`, so `f` gets the id instead
+    /// and fetches the resource itself, for example with [`World::get_resource_mut_by_id`],
+    /// once it's done reading the rest of the [`World`].
+    ///
+    /// Each resource is checked right before `f` is called for it,
+    /// so resources removed by an earlier call are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Multiplier(u32);
+    /// #
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Resource)]
+    /// struct Bonus(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resources((Multiplier(3), Score(10)));
+    ///
+    /// let mut visited = Vec::new();
+    /// world.for_each_resource_with_world::<(Score, Bonus)>(|world, id| {
+    ///     visited.push(id);
+    ///     if Some(id) != world.components().resource_id::<Score>() {
+    ///         return;
+    ///     }
+    ///     let multiplier = world.resource::<Multiplier>().0;
+    ///     let mut resource = world.get_resource_mut_by_id(id).unwrap();
+    ///     // SAFETY: `id` was just checked to be the id of `Score`.
+    ///     let score = unsafe { resource.as_mut().deref_mut::<Score>() };
+    ///     score.0 *= multiplier;
+    /// });
+    ///
+    /// assert_eq!(visited.len(), 1);
+    /// assert_eq!(world.resource::<Score>().0, 30);
+    /// ```
+    fn for_each_resource_with_world<R: ResourceIds>(
+        &mut self,
+        f: impl FnMut(&mut World, ComponentId),
+    );
+}
+
+impl WorldForEachResourceWithWorld for World {
+    fn for_each_resource_with_world<R: ResourceIds>(
+        &mut self,
+        mut f: impl FnMut(&mut World, ComponentId),
+    ) {
+        let slots = R::resource_id_slots(self);
+        for &id in slots.as_ref().iter().flatten() {
+            if self.get_resource_by_id(id).is_some() {
+                f(self, id);
+            }
+        }
+    }
+}
+
+/// Extends [`World`] with `remove_resources`, `remove_resources_bool` and `remove_resources_in_order`.
+pub trait WorldRemoveResources {
+    /// Removes the resources from the [`World`].
+    ///
+    /// Resources that don't exist are ignored.
+    ///
+    /// The values are dropped, so any memory they own on the heap is freed.
+    /// Bevy 0.10 has no way to shrink resource storage, so each resource keeps its [`ComponentId`]
+    /// and a slot the size of the resource itself, which is reused if it's inserted again.
+    /// For large resources, keep the data behind a [`Box`] or [`Vec`] so the retained slot stays small.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// world.remove_resources::<(Score, Lives)>();
+    /// assert!(!world.contains_resource::<Score>());
+    /// assert!(world.components().resource_id::<Score>().is_some());
+    /// ```
+    fn remove_resources<R: RemoveResources>(&mut self);
+
+    /// Removes the resources from the [`World`] like `remove_resources`, and reports which ones existed.
+    ///
+    /// The returned array is in the same order as the tuple,
+    /// with `true` for resources that were removed and `false` for ones that didn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// assert_eq!(world.remove_resources_bool::<(Score, Lives)>(), [true, false]);
+    /// assert!(!world.contains_resource::<Score>());
+    /// ```
+    fn remove_resources_bool<R: RemoveResources>(&mut self) -> R::Removed;
+
+    /// Removes the resources from the [`World`] in reverse order, like local variables going out of scope.
+    ///
+    /// The last resource in the tuple is removed and dropped first, then the one before it, and so on,
+    /// so each resource is dropped before the next one is removed.
+    /// This order is guaranteed, which makes it suitable for resources whose teardown depends on each other,
+    /// such as ones holding OS handles.
+    ///
+    /// Resources that don't exist are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use std::sync::{Arc, Mutex};
+    /// #
+    /// #[derive(Resource)]
+    /// struct Device(Arc<Mutex<Vec<&'static str>>>);
+    ///
+    /// impl Drop for Device {
+    ///     fn drop(&mut self) {
+    ///         self.0.lock().unwrap().push("device");
+    ///     }
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Surface(Arc<Mutex<Vec<&'static str>>>);
+    ///
+    /// impl Drop for Surface {
+    ///     fn drop(&mut self) {
+    ///         self.0.lock().unwrap().push("surface");
+    ///     }
+    /// }
+    ///
+    /// let dropped = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resources((Device(dropped.clone()), Surface(dropped.clone())));
+    ///
+    /// world.remove_resources_in_order::<(Device, Surface)>();
+    /// assert_eq!(*dropped.lock().unwrap(), ["surface", "device"]);
+    /// ```
+    fn remove_resources_in_order<R: RemoveResources>(&mut self);
+}
+
+impl WorldRemoveResources for World {
+    fn remove_resources<R: RemoveResources>(&mut self) {
+        R::remove_resources(self);
+    }
+
+    fn remove_resources_bool<R: RemoveResources>(&mut self) -> R::Removed {
+        R::remove_resources_bool(self)
+    }
+
+    fn remove_resources_in_order<R: RemoveResources>(&mut self) {
+        R::remove_resources_in_order(self);
+    }
+}
+
+/// Extends [`Commands`] with `remove_resources`.
+pub trait CommandsRemoveResources {
+    /// Pushes a [`Command`] to the queue for removing [`Resource`]s from the [`World`].
+    ///
+    /// When the command is applied, [`Resource`]s that don't exist are ignored.
+    ///
+    /// See [`World::remove_resources`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct PlayerScoreboard(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct EnemyScoreboard(u32);
+    /// #
+    /// # fn system(mut commands: Commands) {
+    /// commands.remove_resources::<(PlayerScoreboard, EnemyScoreboard)>();
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn remove_resources<R: RemoveResources>(&mut self);
+}
+
+impl CommandsRemoveResources for Commands<'_, '_> {
+    fn remove_resources<R: RemoveResources>(&mut self) {
+        self.add(RemoveResourcesCommand::<R>::new());
+    }
+}
+
+/// [`Command`] for `remove_resources`.
+pub struct RemoveResourcesCommand<R: RemoveResources> {
+    _phantom: PhantomData<R>,
+}
+
+impl<R: RemoveResources> fmt::Debug for RemoveResourcesCommand<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoveResourcesCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish()
+    }
+}
+
+impl<R: RemoveResources> Command for RemoveResourcesCommand<R> {
+    fn write(self, world: &mut World) {
+        world.remove_resources::<R>();
+    }
+}
+
+impl<R: RemoveResources> Default for RemoveResourcesCommand<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: RemoveResources> RemoveResourcesCommand<R> {
+    /// Creates a [`Command`] which will remove [`Resource`]s from the [`World`]
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData::<R>,
+        }
+    }
+
+    /// Creates a [`Command`] that removes the resources and then passes `f` whether each one existed and was removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::CommandQueue};
+    /// # use bevy_proto_resource_tuples::RemoveResourcesCommand;
+    /// # use std::sync::mpsc;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Lives(3));
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// commands.add(RemoveResourcesCommand::<(Score, Lives)>::with_callback(
+    ///     move |removed| sender.send(removed).unwrap(),
+    /// ));
+    /// queue.apply(&mut world);
+    ///
+    /// assert_eq!(receiver.recv().unwrap(), [false, true]);
+    /// assert!(!world.contains_resource::<Lives>());
+    /// ```
+    pub fn with_callback<F>(f: F) -> RemoveResourcesWithCallbackCommand<R, F>
+    where
+        F: FnOnce(R::Removed) + Send + 'static,
+    {
+        RemoveResourcesWithCallbackCommand {
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// [`Command`] for `remove_resources` that passes which resources were removed to a callback.
+///
+/// Created by [`RemoveResourcesCommand::with_callback`].
+pub struct RemoveResourcesWithCallbackCommand<R: RemoveResources, F> {
+    pub f: F,
+    _phantom: PhantomData<R>,
+}
+
+impl<R: RemoveResources, F> fmt::Debug for RemoveResourcesWithCallbackCommand<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoveResourcesWithCallbackCommand")
+            .field("resources", &format_args!("{}", std::any::type_name::<R>()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R, F> Command for RemoveResourcesWithCallbackCommand<R, F>
+where
+    R: RemoveResources,
+    F: FnOnce(R::Removed) + Send + 'static,
+{
+    fn write(self, world: &mut World) {
+        let removed = R::remove_resources_bool(world);
+        (self.f)(removed);
+    }
+}
+
+/// Extends [`World`] with `get_resources`.
+pub trait WorldGetResources {
+    /// Returns references to the resources, if all of them exist.
+    ///
+    /// If any of the resources don't exist, `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    /// assert!(world.get_resources::<(Score, Lives)>().is_none());
+    ///
+    /// world.insert_resource(Lives(3));
+    /// let (score, lives) = world.get_resources::<(Score, Lives)>().unwrap();
+    /// assert_eq!((score.0, lives.0), (10, 3));
+    /// ```
+    fn get_resources<R: GetResources>(&self) -> Option<R::Refs<'_>>;
+}
+
+impl WorldGetResources for World {
+    fn get_resources<R: GetResources>(&self) -> Option<R::Refs<'_>> {
+        R::get_resources(self)
+    }
+}
+
+/// Extends [`World`] with `reset_resources`.
+pub trait WorldResetResources {
+    /// Overwrites each resource with its [`Default`] value, inserting it if it doesn't exist,
+    /// and returns whether each one existed and was reset, rather than being created.
+    ///
+    /// Unlike `init_resources`, existing resources aren't skipped,
+    /// which makes this useful for clearing accumulators at the start of a frame.
+    ///
+    /// # Panics
+    ///
+    /// If dropping one of the previous values panics, the panic is propagated and the rest aren't reset.
+    /// With the `catch_drop_panics` feature, the panic is caught and logged instead,
+    /// and the previous values are removed before being dropped, so reset resources count as added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Hits(u32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Misses(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Hits(12));
+    ///
+    /// assert_eq!(world.reset_resources::<(Hits, Misses)>(), [true, false]);
+    /// assert_eq!(world.resource::<Hits>().0, 0);
+    /// assert_eq!(world.resource::<Misses>().0, 0);
+    /// ```
+    fn reset_resources<R: ResetResources>(&mut self) -> R::Reset;
+}
+
+impl WorldResetResources for World {
+    fn reset_resources<R: ResetResources>(&mut self) -> R::Reset {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let reset = R::reset_resources(self);
+        end_batch(self, batch);
+        reset
+    }
+}
+
+/// Extends [`World`] with `resource_ptrs`.
+pub trait WorldResourcePtrs {
+    /// Returns untyped pointers to the resources, with `None` for each resource that doesn't exist.
+    ///
+    /// This is the same as [`World::get_resource_by_id`] for each resource,
+    /// meant for FFI or custom reflection where the typed API isn't usable.
+    /// **Prefer [`get_resources`](WorldGetResources::get_resources) where possible.**
+    ///
+    /// Getting the pointers is safe, but dereferencing them with [`Ptr::deref`] is not.
+    /// The caller must uphold that:
+    /// - each pointer is only dereferenced as the type of the resource in the same position;
+    /// - the pointers aren't used to modify the resources,
+    ///   since they're derived from a shared borrow of the [`World`];
+    /// - the pointers aren't used after the borrow of the [`World`] ends,
+    ///   since inserting, removing or mutating resources afterwards may move or change them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// let (score, lives) = world.resource_ptrs::<(Score, Lives)>();
+    /// assert!(lives.is_none());
+    ///
+    /// // SAFETY: the pointer is to a `Score` and the world is still borrowed.
+    /// let score = unsafe { score.unwrap().deref::<Score>() };
+    /// assert_eq!(score.0, 10);
+    /// ```
+    fn resource_ptrs<R: ResourcePtrs>(&self) -> R::Ptrs<'_>;
+}
+
+impl WorldResourcePtrs for World {
+    fn resource_ptrs<R: ResourcePtrs>(&self) -> R::Ptrs<'_> {
+        R::resource_ptrs(self)
+    }
+}
+
+/// Extends [`World`] with `take_resources` and `try_take_resources`.
+pub trait WorldTakeResources {
+    /// Removes the resources from the [`World`] and returns them.
+    ///
+    /// If any of the resources don't exist, `None` is returned and nothing is removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// assert!(world.take_resources::<(Score, Lives)>().is_none());
+    /// assert!(world.contains_resource::<Score>());
+    ///
+    /// world.insert_resource(Lives(3));
+    /// let (score, lives) = world.take_resources::<(Score, Lives)>().unwrap();
+    /// assert_eq!((score.0, lives.0), (10, 3));
+    /// assert!(!world.contains_resource::<Score>());
+    /// ```
+    fn take_resources<R: RemoveResources>(&mut self) -> Option<R>;
+
+    /// Removes whichever of the resources exist from the [`World`] and returns them.
+    ///
+    /// Unlike `take_resources`, this doesn't require all of the resources to exist:
+    /// resources that don't exist have `None` in their place, and the rest are still removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// let (score, lives) = world.try_take_resources::<(Score, Lives)>();
+    /// assert_eq!(score.map(|score| score.0), Some(10));
+    /// assert!(lives.is_none());
+    /// assert!(!world.contains_resource::<Score>());
+    /// ```
+    fn try_take_resources<R: RemoveResources>(&mut self) -> R::Partial;
+}
+
+impl WorldTakeResources for World {
+    fn take_resources<R: RemoveResources>(&mut self) -> Option<R> {
+        R::take_resources(self)
+    }
+
+    fn try_take_resources<R: RemoveResources>(&mut self) -> R::Partial {
+        R::try_take_resources(self)
+    }
+}
+
+/// Extends [`World`] with `update_resources`.
+pub trait WorldUpdateResources {
+    /// Takes the resources out of the [`World`], transforms them with the closure, and inserts the results.
+    ///
+    /// If any of the resources don't exist, the closure isn't called, nothing changes and `false` is returned.
+    ///
+    /// If the closure panics, the taken resources are dropped and not reinserted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Version(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Volume(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Version(1), Volume(50.0)));
+    ///
+    /// let migrated = world.update_resources(|(version, volume): (Version, Volume)| {
+    ///     (Version(version.0 + 1), Volume(volume.0 / 100.0))
+    /// });
+    ///
+    /// assert!(migrated);
+    /// assert_eq!(world.resource::<Version>().0, 2);
+    /// assert_eq!(world.resource::<Volume>().0, 0.5);
+    /// ```
+    fn update_resources<R: RemoveResources + InsertResources>(
+        &mut self,
+        f: impl FnOnce(R) -> R,
+    ) -> bool;
+
+    /// Transforms each resource with its own closure from `f`, a tuple with one closure per resource.
+    ///
+    /// Each resource is taken out of the [`World`], passed to its closure, and the result is inserted in its place,
+    /// before moving on to the next one.
+    /// Unlike `update_resources`, missing resources don't stop the others from being transformed:
+    /// their closures are skipped, and `false` is returned in their place.
+    ///
+    /// If a closure panics, the resources before it have already been transformed and reinserted,
+    /// the ones after it are left untouched, and the one it was given is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Version(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Volume(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Brightness(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Version(1), Volume(50.0)));
+    ///
+    /// let mapped = world.map_resources::<(Version, Volume, Brightness), _>((
+    ///     |version: Version| Version(version.0 + 1),
+    ///     |volume: Volume| Volume(volume.0 / 100.0),
+    ///     |brightness: Brightness| Brightness(brightness.0 / 100.0),
+    /// ));
+    ///
+    /// assert_eq!(mapped, [true, true, false]);
+    /// assert_eq!(world.resource::<Version>().0, 2);
+    /// assert_eq!(world.resource::<Volume>().0, 0.5);
+    /// ```
+    fn map_resources<R: MapResources<F>, F>(&mut self, f: F) -> R::Mapped;
+}
+
+impl WorldUpdateResources for World {
+    fn update_resources<R: RemoveResources + InsertResources>(
+        &mut self,
+        f: impl FnOnce(R) -> R,
+    ) -> bool {
+        match R::take_resources(self) {
+            Some(resources) => {
+                self.insert_resources(f(resources));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn map_resources<R: MapResources<F>, F>(&mut self, f: F) -> R::Mapped {
+        R::map_resources(self, f)
+    }
+}
+
+/// Extends [`World`] with `swap_resources`.
+pub trait WorldSwapResources {
+    /// Replaces the resources with `incoming` and returns the previous values,
+    /// if all of the resources exist.
+    ///
+    /// If any of the resources don't exist, nothing is changed and `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Front(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Back(u32);
+    /// #
+    /// let mut world = World::new();
+    /// assert!(world.swap_resources((Front(1), Back(2))).is_none());
+    /// assert!(!world.contains_resource::<Front>());
+    ///
+    /// world.insert_resources((Front(0), Back(0)));
+    /// let (front, back) = world.swap_resources((Front(1), Back(2))).unwrap();
+    ///
+    /// assert_eq!((front.0, back.0), (0, 0));
+    /// assert_eq!(world.resource::<Front>().0, 1);
+    /// ```
+    fn swap_resources<R: RemoveResources + InsertResources>(&mut self, incoming: R) -> Option<R>;
+}
+
+impl WorldSwapResources for World {
+    fn swap_resources<R: RemoveResources + InsertResources>(&mut self, incoming: R) -> Option<R> {
+        let previous = R::take_resources(self)?;
+        self.insert_resources(incoming);
+        Some(previous)
+    }
+}
+
+/// Reinserts the resources taken by `resources_scope` when dropped, even while unwinding.
+struct ResourcesScopeGuard<'w, R: InsertResources> {
+    world: &'w mut World,
+    resources: Option<R>,
+}
+
+impl<R: InsertResources> Drop for ResourcesScopeGuard<'_, R> {
+    fn drop(&mut self) {
+        if let Some(resources) = self.resources.take() {
+            self.world.insert_resources(resources);
+        }
+    }
+}
+
+/// Extends [`World`] with `resources_scope`.
+pub trait WorldResourcesScope {
+    /// Temporarily removes the resources from the [`World`], runs the closure with mutable access to them
+    /// alongside the [`World`], and then reinserts them in order.
+    ///
+    /// This is the same as [`World::resource_scope`], but for multiple resources.
+    /// The resources are reinserted even if the closure panics.
+    ///
+    /// Unlike [`World::resource_scope`], the resources are reinserted as new values,
+    /// so they will be detected as added and changed afterwards.
+    /// Any resource of the same type inserted inside the closure is overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the resources don't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Multiplier(u32);
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Points(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(0), Multiplier(2)));
+    /// world.spawn(Points(5));
+    ///
+    /// world.resources_scope(|world, (score, multiplier): &mut (Score, Multiplier)| {
+    ///     for points in world.query::<&Points>().iter(world) {
+    ///         score.0 += points.0 * multiplier.0;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    ///
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     world.resources_scope(|_, _: &mut (Score, Multiplier)| panic!());
+    /// }));
+    /// assert!(result.is_err());
+    /// assert!(world.contains_resource::<Score>());
+    /// assert!(world.contains_resource::<Multiplier>());
+    /// ```
+    fn resources_scope<R: RemoveResources + InsertResources, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, &mut R) -> U,
+    ) -> U;
+}
+
+impl WorldResourcesScope for World {
+    fn resources_scope<R: RemoveResources + InsertResources, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, &mut R) -> U,
+    ) -> U {
+        let resources = R::take_resources(self)
+            .unwrap_or_else(|| panic!("resources do not exist: {}", std::any::type_name::<R>()));
+        let mut guard = ResourcesScopeGuard {
+            world: self,
+            resources: Some(resources),
+        };
+        let resources = guard.resources.as_mut().unwrap();
+        f(guard.world, resources)
+    }
+}
+
+/// Removes the resources inserted by `with_resources` when dropped and restores the previous values,
+/// even while unwinding.
+struct WithResourcesGuard<'w, R: RemoveResources>
+where
+    R::Partial: InsertOptionalResources,
+{
+    world: &'w mut World,
+    previous: Option<R::Partial>,
+}
+
+impl<R: RemoveResources> Drop for WithResourcesGuard<'_, R>
+where
+    R::Partial: InsertOptionalResources,
+{
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            R::remove_resources(self.world);
+            self.world.insert_optional_resources(previous);
+        }
+    }
+}
+
+/// Extends [`World`] with `with_resources`.
+pub trait WorldWithResources {
+    /// Inserts the resources, runs the closure, and then removes them again.
+    ///
+    /// Resources of the same types that existed beforehand are set aside while the closure runs
+    /// and restored afterwards, instead of being removed.
+    /// The cleanup happens even if the closure panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// let total = world.with_resources((Score(1), Lives(3)), |world| {
+    ///     world.resource::<Score>().0 + world.resource::<Lives>().0
+    /// });
+    ///
+    /// assert_eq!(total, 4);
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert!(!world.contains_resource::<Lives>());
+    ///
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     world.with_resources((Lives(3),), |_| panic!());
+    /// }));
+    /// assert!(result.is_err());
+    /// assert!(!world.contains_resource::<Lives>());
+    /// ```
+    fn with_resources<R: RemoveResources + InsertResources, T>(
+        &mut self,
+        resources: R,
+        f: impl FnOnce(&mut World) -> T,
+    ) -> T
+    where
+        R::Partial: InsertOptionalResources;
+}
+
+impl WorldWithResources for World {
+    fn with_resources<R: RemoveResources + InsertResources, T>(
+        &mut self,
+        resources: R,
+        f: impl FnOnce(&mut World) -> T,
+    ) -> T
+    where
+        R::Partial: InsertOptionalResources,
+    {
+        let previous = R::try_take_resources(self);
+        self.insert_resources(resources);
+        let guard = WithResourcesGuard::<R> {
+            world: self,
+            previous: Some(previous),
+        };
+        f(guard.world)
+    }
+}
+
+/// A guard for tests that initializes a group of resources and removes the ones it created when dropped.
+///
+/// Resources that already existed are left alone, so fixtures can be nested or share resources with the test.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::ResourceGroupFixture;
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #
+/// # #[derive(Resource, Default)]
+/// # struct Lives(u32);
+/// #
+/// let mut world = World::new();
+/// world.insert_resource(Score(10));
+///
+/// {
+///     let mut fixture = ResourceGroupFixture::<(Score, Lives)>::new(&mut world);
+///     assert_eq!(fixture.created(), &[false, true]);
+///     fixture.world().resource_mut::<Lives>().0 = 3;
+/// }
+///
+/// assert_eq!(world.resource::<Score>().0, 10);
+/// assert!(!world.contains_resource::<Lives>());
+/// ```
+pub struct ResourceGroupFixture<'w, R: InitResources>
+where
+    R::IDS: AsRef<[ComponentId]>,
+{
+    world: &'w mut World,
+    ids: R::IDS,
+    created: Vec<bool>,
+}
+
+impl<'w, R: InitResources> ResourceGroupFixture<'w, R>
+where
+    R::IDS: AsRef<[ComponentId]>,
+{
+    /// Initializes the resources, recording which of them didn't exist yet.
+    #[track_caller]
+    pub fn new(world: &'w mut World) -> Self {
+        let (ids, created) = init_resources_created::<R>(world);
+        Self {
+            world,
+            ids,
+            created,
+        }
+    }
+
+    /// Returns whether each resource was created by this fixture, and will be removed when it's dropped.
+    pub fn created(&self) -> &[bool] {
+        &self.created
+    }
+
+    /// Returns the underlying [`World`].
+    pub fn world(&mut self) -> &mut World {
+        self.world
+    }
+}
+
+impl<R: InitResources> Drop for ResourceGroupFixture<'_, R>
+where
+    R::IDS: AsRef<[ComponentId]>,
+{
+    fn drop(&mut self) {
+        for (&id, &created) in self.ids.as_ref().iter().zip(&self.created) {
+            if created {
+                self.world.remove_resource_by_id(id);
+            }
+        }
+    }
+}
+
+impl<R: InitResources> fmt::Debug for ResourceGroupFixture<'_, R>
+where
+    R::IDS: AsRef<[ComponentId]> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceGroupFixture")
+            .field("ids", &self.ids)
+            .field("created", &self.created)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Extends [`World`] with `insert_optional_resources`.
+pub trait WorldInsertOptionalResources {
+    /// Inserts the resources that are `Some` and returns the [`ComponentId`]s for them.
+    ///
+    /// Resources that are `None` are skipped and have `None` in place of their [`ComponentId`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut world = World::new();
+    /// let windy = false;
+    ///
+    /// let (gravity, wind) = world.insert_optional_resources((
+    ///     Some(Gravity(9.8)),
+    ///     windy.then_some(Wind(2.0)),
+    /// ));
+    ///
+    /// assert!(gravity.is_some() && wind.is_none());
+    /// assert!(!world.contains_resource::<Wind>());
+    /// ```
+    fn insert_optional_resources<R: InsertOptionalResources>(&mut self, resources: R) -> R::IDS;
+}
+
+impl WorldInsertOptionalResources for World {
+    fn insert_optional_resources<R: InsertOptionalResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, |types| {
+            resources.resource_types(types)
+        });
+        let ids = resources.insert_optional_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+}
+
+/// Extends [`App`] with `insert_optional_resources`.
+pub trait AppInsertOptionalResources {
+    /// Inserts the [`Resource`]s that are `Some` to the current [`App`],
+    /// overwriting any [`Resource`] previously added of the same type.
+    ///
+    /// [`Resource`]s that are `None` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct MyCounter(usize);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct MyValue(f32);
+    /// #
+    /// # let value = None;
+    /// App::new()
+    ///     .insert_optional_resources((Some(MyCounter(0)), value.map(MyValue)));
+    /// ```
+    fn insert_optional_resources<R: InsertOptionalResources>(&mut self, resources: R) -> &mut Self;
+}
+
+impl AppInsertOptionalResources for App {
+    fn insert_optional_resources<R: InsertOptionalResources>(&mut self, resources: R) -> &mut Self {
+        self.world.insert_optional_resources(resources);
+        self
+    }
+}
+
+/// Extends [`World`] with `insert_boxed_resources`.
+pub trait WorldInsertBoxedResources {
+    /// Unboxes and inserts the resources, returning the [`ComponentId`]s for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut world = World::new();
+    ///
+    /// world.insert_boxed_resources((Box::new(Gravity(9.8)), Box::new(Wind(2.0))));
+    ///
+    /// assert_eq!(world.resource::<Gravity>().0, 9.8);
+    /// assert_eq!(world.resource::<Wind>().0, 2.0);
+    /// ```
+    fn insert_boxed_resources<R: InsertBoxedResources>(&mut self, resources: R) -> R::IDS;
+}
+
+impl WorldInsertBoxedResources for World {
+    fn insert_boxed_resources<R: InsertBoxedResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_boxed_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+}
+
+/// Extends [`World`] with `insert_cow_resources`.
+pub trait WorldInsertCowResources {
+    /// Inserts resources that are either borrowed or owned, returning the [`ComponentId`]s for them.
+    ///
+    /// Borrowed resources are cloned, and owned ones are moved without cloning.
+    /// This is useful when some of the values come from elsewhere by reference and others are constructed on the spot.
+    /// `Cow::Owned` can't infer the resource type from the value, so it needs to be spelled out, as in `Cow::<Seed>::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use std::borrow::Cow;
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Palette(Vec<u32>);
+    /// #
+    /// # #[derive(Resource, Clone)]
+    /// # struct Seed(u64);
+    /// #
+    /// let shared = Palette(vec![0xff0000, 0x00ff00]);
+    ///
+    /// let mut world = World::new();
+    /// world.insert_cow_resources((Cow::Borrowed(&shared), Cow::<Seed>::Owned(Seed(42))));
+    ///
+    /// assert_eq!(world.resource::<Palette>().0, shared.0);
+    /// assert_eq!(world.resource::<Seed>().0, 42);
+    /// ```
+    fn insert_cow_resources<R: InsertCowResources>(&mut self, resources: R) -> R::IDS;
+}
+
+impl WorldInsertCowResources for World {
+    #[track_caller]
+    fn insert_cow_resources<R: InsertCowResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_cow_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+}
+
+/// Extends [`World`] with `insert_result_resources`.
+pub trait WorldInsertResultResources {
+    /// Inserts resources that were each constructed fallibly, returning the first error instead if any of them failed.
+    ///
+    /// The results are checked from left to right before anything is inserted,
+    /// so if one is an `Err`, none of the resources from this call are inserted
+    /// and any existing values are left untouched, with no need to roll back.
+    /// This is named differently from [`WorldTryInsertResources::try_insert_resources`],
+    /// which fails when resources already exist rather than when constructing them fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Port(u16);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Workers(usize);
+    /// #
+    /// fn parse_port(s: &str) -> Result<Port, String> {
+    ///     s.parse().map(Port).map_err(|err| format!("bad port `{s}`: {err}"))
+    /// }
+    ///
+    /// fn parse_workers(s: &str) -> Result<Workers, String> {
+    ///     s.parse().map(Workers).map_err(|err| format!("bad worker count `{s}`: {err}"))
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let result = world.insert_result_resources((parse_port("8080"), parse_workers("many")));
+    /// assert!(result.is_err());
+    /// assert!(!world.contains_resource::<Port>());
+    ///
+    /// world
+    ///     .insert_result_resources((parse_port("8080"), parse_workers("4")))
+    ///     .unwrap();
+    /// assert_eq!(world.resource::<Port>().0, 8080);
+    /// ```
+    fn insert_result_resources<R: InsertResultResources<E>, E>(
+        &mut self,
+        results: R,
+    ) -> Result<R::IDS, E>;
+}
 
-/// Resources that can be initialized in the [`World`] together.
-pub trait InitResources: Send + Sync + 'static {
-    type IDS;
+impl WorldInsertResultResources for World {
+    #[track_caller]
+    fn insert_result_resources<R: InsertResultResources<E>, E>(
+        &mut self,
+        results: R,
+    ) -> Result<R::IDS, E> {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = results.insert_result_resources(self)?;
+        end_batch(self, batch);
+        Ok(ids)
+    }
+}
 
-    fn init_resources(world: &mut World) -> Self::IDS;
+/// Extends [`World`] with `insert_erased_resources`.
+pub trait WorldInsertErasedResources {
+    /// Inserts each of the type-erased resources in order,
+    /// overwriting any resource previously added of the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, ErasedResource};
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut registry: Vec<Box<dyn ErasedResource>> = Vec::new();
+    /// registry.push(Box::new(Gravity(9.8)));
+    /// registry.push(Box::new(Wind(2.0)));
+    ///
+    /// let mut world = World::new();
+    /// world.insert_erased_resources(registry);
+    ///
+    /// assert_eq!(world.resource::<Gravity>().0, 9.8);
+    /// assert_eq!(world.resource::<Wind>().0, 2.0);
+    /// ```
+    fn insert_erased_resources<I>(&mut self, resources: I)
+    where
+        I: IntoIterator<Item = Box<dyn ErasedResource>>;
 }
 
-/// Resources that can be inserted into the [`World`] together.
-pub trait InsertResources: Send + Sync + 'static {
-    fn insert_resources(self, world: &mut World);
+impl WorldInsertErasedResources for World {
+    fn insert_erased_resources<I>(&mut self, resources: I)
+    where
+        I: IntoIterator<Item = Box<dyn ErasedResource>>,
+    {
+        for resource in resources {
+            resource.insert_into(self);
+        }
+    }
 }
 
-/// Extends [`World`] with `init_resources`.
-pub trait WorldInitResources {
-    /// Initializes new resources and returns a vector of the [`ComponentId`]s created for them.
+/// Extends [`World`] with `insert_or_init_resources`.
+pub trait WorldInsertOrInitResources {
+    /// Inserts the resources that have a value and initializes the rest, in order,
+    /// returning the [`ComponentId`]s for all of them.
     ///
-    /// If a resource already exists, nothing happens.
+    /// [`OrDefault::Value`] overwrites any existing resource, like `insert_resources`.
+    /// [`OrDefault::Default`] uses [`FromWorld`] and does nothing if the resource already exists, like `init_resources`.
     ///
-    /// The value given by the [`FromWorld::from_world`] method will be used.
-    /// Note that any resource with the [`Default`] trait automatically implements [`FromWorld`],
-    /// and those default values will be here instead.
-    fn init_resources<R: InitResources>(&mut self) -> R::IDS;
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, OrDefault};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_or_init_resources((OrDefault::Value(Gravity(9.8)), OrDefault::<Wind>::Default));
+    ///
+    /// assert_eq!(world.resource::<Gravity>().0, 9.8);
+    /// assert_eq!(world.resource::<Wind>().0, 0.0);
+    /// ```
+    fn insert_or_init_resources<R: InsertOrInitResources>(&mut self, resources: R) -> R::IDS;
 }
 
-impl WorldInitResources for World {
-    fn init_resources<R: InitResources>(&mut self) -> R::IDS {
-        R::init_resources(self)
+impl WorldInsertOrInitResources for World {
+    #[track_caller]
+    fn insert_or_init_resources<R: InsertOrInitResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_or_init_resources(self);
+        end_batch(self, batch);
+        ids
     }
 }
 
-/// Extends [`App`] with `init_resources`.
-pub trait AppInitResources {
-    /// Initialize a [`Resource`] with standard starting values by adding it to the [`World`].
+/// Extends [`World`] with `insert_filled_resources`.
+pub trait WorldInsertFilledResources {
+    /// Inserts the values and initializes the resources marked with [`Fill`], in order,
+    /// returning the [`ComponentId`]s for all of them.
     ///
-    /// If the [`Resource`] already exists, nothing happens.
+    /// Values overwrite any existing resource, like `insert_resources`.
+    /// [`Fill`] uses [`FromWorld`] and does nothing if the resource already exists, like `init_resources`.
     ///
-    /// The [`Resource`] must implement the [`FromWorld`] trait.
-    /// If the [`Default`] trait is implemented, the [`FromWorld`] trait will use
-    /// the [`Default::default`] method to initialize the [`Resource`].
+    /// Only tuples of up to 4 elements are supported.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
-    /// # use bevy_app::prelude::*;
     /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, Fill};
     /// #
-    /// #[derive(Resource)]
-    /// struct MyCounter {
-    ///     counter: usize,
-    /// }
+    /// # #[derive(Resource)]
+    /// # struct Gravity(f32);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Wind(f32);
+    /// #
+    /// let mut world = World::new();
+    /// world.insert_filled_resources((Gravity(9.8), Fill::<Wind>::new()));
     ///
-    /// impl Default for MyCounter {
-    ///     fn default() -> MyCounter {
-    ///         MyCounter {
-    ///             counter: 100
-    ///         }
-    ///     }
-    /// }
+    /// assert_eq!(world.resource::<Gravity>().0, 9.8);
+    /// assert_eq!(world.resource::<Wind>().0, 0.0);
+    /// ```
+    fn insert_filled_resources<R: InsertFilledResources>(&mut self, resources: R) -> R::IDS;
+}
+
+impl WorldInsertFilledResources for World {
+    #[track_caller]
+    fn insert_filled_resources<R: InsertFilledResources>(&mut self, resources: R) -> R::IDS {
+        let batch = begin_batch(self, BatchKind::Insert, R::resource_types);
+        let ids = resources.insert_filled_resources(self);
+        end_batch(self, batch);
+        ids
+    }
+}
+
+/// Extends [`App`] with `insert_or_init_resources`.
+pub trait AppInsertOrInitResources {
+    /// Inserts the [`Resource`]s that have a value and initializes the rest, in order.
     ///
-    /// #[derive(Resource)]
-    /// struct MyValue {
-    ///     value: f32,
-    /// }
+    /// See [`World::insert_or_init_resources`] for more details.
     ///
-    /// impl Default for MyValue {
-    ///     fn default() -> MyValue {
-    ///         MyValue {
-    ///             value: 20.0
-    ///         }
-    ///     }
-    /// }
+    /// # Examples
     ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::{prelude::*, OrDefault};
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyCounter(usize);
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct MyValue(f32);
+    /// #
     /// App::new()
-    ///     .init_resources::<(MyCounter, MyValue)>();
+    ///     .insert_or_init_resources((OrDefault::Value(MyCounter(10)), OrDefault::<MyValue>::Default));
     /// ```
-    fn init_resources<R: InitResources>(&mut self) -> &mut Self;
+    fn insert_or_init_resources<R: InsertOrInitResources>(&mut self, resources: R) -> &mut Self;
 }
 
-impl AppInitResources for App {
-    fn init_resources<R: InitResources>(&mut self) -> &mut Self {
-        self.world.init_resources::<R>();
+impl AppInsertOrInitResources for App {
+    #[track_caller]
+    fn insert_or_init_resources<R: InsertOrInitResources>(&mut self, resources: R) -> &mut Self {
+        self.world.insert_or_init_resources(resources);
         self
     }
 }
 
-/// Extends [`Commands`] with `init_resources`.
-pub trait CommandsInitResources {
-    /// Pushes a [`Command`] to the queue for inserting a [`Resource`] in the [`World`] with an inferred value.
-    ///
-    /// The inferred value is determined by the [`FromWorld`] trait of the resource.
-    /// When the command is applied,
-    /// if the resource already exists, nothing happens.
+/// Extends [`World`] with `insert_resources_cloned`.
+pub trait WorldInsertResourcesCloned {
+    /// Inserts clones of the resources and returns the [`ComponentId`]s for them.
     ///
-    /// See [`World::init_resource`] for more details.
+    /// This is useful for inserting the same resources into multiple [`World`]s
+    /// without constructing them again.
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
     /// #
-    /// # #[derive(Resource, Default)]
-    /// # struct PlayerScoreboard {
-    /// #     current_score: u32,
-    /// #     high_score: u32,
-    /// # }
+    /// # #[derive(Resource, Clone)]
+    /// # struct Gravity(f32);
     /// #
-    /// # #[derive(Resource, Default)]
-    /// # struct EnemyScoreboard {
-    /// #     current_score: u32,
-    /// #     high_score: u32,
-    /// # }
+    /// # #[derive(Resource, Clone)]
+    /// # struct Wind(f32);
     /// #
-    /// # fn initialise_scoreboards(mut commands: Commands) {
-    /// commands.init_resources::<(PlayerScoreboard, EnemyScoreboard)>();
-    /// # }
-    /// # bevy_ecs::system::assert_is_system(initialise_scoreboards);
+    /// let config = (Gravity(9.8), Wind(2.0));
+    ///
+    /// let mut main_world = World::new();
+    /// let mut test_world = World::new();
+    /// main_world.insert_resources_cloned(&config);
+    /// test_world.insert_resources_cloned(&config);
+    ///
+    /// assert_eq!(main_world.resource::<Wind>().0, test_world.resource::<Wind>().0);
     /// ```
-    fn init_resources<R: InitResources>(&mut self);
+    fn insert_resources_cloned<R: InsertResources + Clone>(&mut self, resources: &R) -> R::IDS;
 }
 
-impl CommandsInitResources for Commands<'_, '_> {
-    fn init_resources<R: InitResources>(&mut self) {
-        self.add(InitResourcesCommand::<R>::new())
+impl WorldInsertResourcesCloned for World {
+    fn insert_resources_cloned<R: InsertResources + Clone>(&mut self, resources: &R) -> R::IDS {
+        self.insert_resources(resources.clone())
     }
 }
 
-/// [`Command`] for `init_resources`.
-pub struct InitResourcesCommand<R: InitResources> {
-    _phantom: PhantomData<R>,
+/// Resources that can be initialized in the [`World`] together while timing each initialization.
+#[cfg(feature = "timing")]
+pub trait InitResourcesTimed: Send + Sync + 'static {
+    type Durations: AsRef<[std::time::Duration]>;
+
+    fn init_resources_timed(world: &mut World) -> Self::Durations;
+
+    /// Appends the [`TypeId`] and type name of each resource to `types`, in order.
+    fn resource_types(types: &mut Vec<(TypeId, &'static str)>);
 }
 
-impl<R: InitResources> Command for InitResourcesCommand<R> {
-    fn write(self, world: &mut World) {
-        world.init_resources::<R>();
+/// Initializes the resource, returning how long its [`FromWorld`] implementation took,
+/// or zero if it already existed.
+#[cfg(feature = "timing")]
+#[track_caller]
+fn init_resource_timed<R: Resource + FromWorld>(world: &mut World) -> std::time::Duration {
+    if world.contains_resource::<R>() {
+        return std::time::Duration::ZERO;
     }
+    let start = std::time::Instant::now();
+    init_resource_checked::<R>(world);
+    start.elapsed()
 }
 
-impl<R: InitResources> InitResourcesCommand<R> {
-    /// Creates a [`Command`] which will insert a default created [`Resource`] into the [`World`]
-    pub const fn new() -> Self {
-        Self {
-            _phantom: PhantomData::<R>,
-        }
+/// Extends [`World`] with `init_resources_timed`.
+#[cfg(feature = "timing")]
+pub trait WorldInitResourcesTimed {
+    /// Initializes new resources like `init_resources`,
+    /// but returns how long each resource took to initialize instead of the [`ComponentId`]s.
+    ///
+    /// Resources that already exist are skipped and have a zero duration.
+    ///
+    /// Requires the `timing` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// #
+    /// # #[derive(Resource, Default)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Terrain(Vec<f32>);
+    /// #
+    /// impl FromWorld for Terrain {
+    ///     fn from_world(_world: &mut World) -> Self {
+    ///         std::thread::sleep(Duration::from_millis(5));
+    ///         Terrain(vec![0.0; 1024])
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(10));
+    ///
+    /// let [score, terrain] = world.init_resources_timed::<(Score, Terrain)>();
+    /// assert_eq!(score, Duration::ZERO);
+    /// assert!(terrain >= Duration::from_millis(5));
+    /// ```
+    fn init_resources_timed<R: InitResourcesTimed>(&mut self) -> R::Durations;
+}
+
+#[cfg(feature = "timing")]
+impl WorldInitResourcesTimed for World {
+    #[track_caller]
+    fn init_resources_timed<R: InitResourcesTimed>(&mut self) -> R::Durations {
+        let batch = begin_batch(self, BatchKind::Init, R::resource_types);
+        let durations = R::init_resources_timed(self);
+        end_batch(self, batch);
+        durations
     }
 }
 
-/// Extends [`World`] with `insert_resources`.
-pub trait WorldInsertResources {
-    fn insert_resources<R: InsertResources>(&mut self, resources: R);
+/// Resources that can be cloned out of the [`World`] through reflection together.
+#[cfg(feature = "reflect")]
+pub trait ReflectResources: Send + Sync + 'static {
+    fn clone_resources_reflect(
+        world: &World,
+        registry: &bevy_reflect::TypeRegistry,
+    ) -> ReflectedResources;
 }
 
-impl WorldInsertResources for World {
-    /// Inserts a new resource with the given `value`.
+/// Resources cloned out of the [`World`] through reflection, returned by `clone_resources_reflect`.
+#[cfg(feature = "reflect")]
+#[derive(Debug, Default)]
+pub struct ReflectedResources {
+    /// The cloned values of the resources that exist, in order.
+    pub values: Vec<Box<dyn bevy_reflect::Reflect>>,
+    /// The type names of the resources that don't have [`ReflectResource`] registered.
     ///
-    /// Resources are "unique" data of a given type.
-    /// If you insert a resource of a type that already exists,
-    /// you will overwrite any existing data.
-    fn insert_resources<R: InsertResources>(&mut self, resources: R) {
-        resources.insert_resources(self);
+    /// [`ReflectResource`]: bevy_ecs::reflect::ReflectResource
+    pub unregistered: Vec<&'static str>,
+}
+
+#[cfg(feature = "reflect")]
+impl ReflectedResources {
+    fn push<R: Resource>(&mut self, world: &World, registry: &bevy_reflect::TypeRegistry) {
+        match registry.get_type_data::<bevy_ecs::reflect::ReflectResource>(TypeId::of::<R>()) {
+            Some(reflect_resource) => {
+                if let Some(resource) = reflect_resource.reflect(world) {
+                    self.values.push(resource.clone_value());
+                }
+            }
+            None => self.unregistered.push(std::any::type_name::<R>()),
+        }
     }
 }
 
-/// Extends [`App`] with `insert_resources`.
-pub trait AppInsertResources {
-    /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
+/// Extends [`World`] with `clone_resources_reflect` and `apply_resources_reflect`.
+#[cfg(feature = "reflect")]
+pub trait WorldReflectResources {
+    /// Clones the resources out of the [`World`] through reflection, without requiring [`Clone`].
     ///
-    /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
-    /// before using them. This happens with [`insert_resources`](Self::insert_resources).
+    /// Resources that don't exist are skipped.
+    /// Resources that don't have [`ReflectResource`] registered are skipped too,
+    /// and their type names are collected in [`ReflectedResources::unregistered`].
     ///
-    /// See `init_resources` for [`Resource`]s that implement [`Default`] or [`FromWorld`].
+    /// Requires the `reflect` feature.
     ///
-    /// # Examples
+    /// [`ReflectResource`]: bevy_ecs::reflect::ReflectResource
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use bevy_app::prelude::*;
-    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::{prelude::*, reflect::ReflectResource};
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
     /// #
-    /// #[derive(Resource)]
-    /// struct MyCounter {
-    ///     counter: usize,
-    /// }
+    /// # #[derive(Resource, Reflect, Default)]
+    /// # #[reflect(Resource)]
+    /// # struct Score(u32);
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Lives(u32);
+    /// #
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Score>();
     ///
-    /// #[derive(Resource)]
-    /// struct MyValue {
-    ///     value: f32,
-    /// }
+    /// let mut world = World::new();
+    /// world.insert_resources((Score(10), Lives(3)));
     ///
-    /// App::new()
-    ///    .insert_resources((MyCounter { counter: 0 }, MyValue { value: 1.0 }));
+    /// let snapshot = world.clone_resources_reflect::<(Score, Lives)>(&registry);
+    /// assert_eq!(snapshot.values.len(), 1);
+    /// assert_eq!(snapshot.unregistered, [std::any::type_name::<Lives>()]);
+    ///
+    /// world.resource_mut::<Score>().0 = 0;
+    /// world.apply_resources_reflect(&registry, snapshot.values);
+    /// assert_eq!(world.resource::<Score>().0, 10);
     /// ```
-    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self;
+    fn clone_resources_reflect<R: ReflectResources>(
+        &self,
+        registry: &bevy_reflect::TypeRegistry,
+    ) -> ReflectedResources;
+
+    /// Writes the reflected resources back into the [`World`],
+    /// applying them to the existing resources or inserting them if they don't exist.
+    ///
+    /// Values whose type doesn't have [`ReflectResource`] registered are skipped,
+    /// and their type names are returned.
+    ///
+    /// Requires the `reflect` feature.
+    ///
+    /// [`ReflectResource`]: bevy_ecs::reflect::ReflectResource
+    fn apply_resources_reflect<I>(
+        &mut self,
+        registry: &bevy_reflect::TypeRegistry,
+        values: I,
+    ) -> Vec<String>
+    where
+        I: IntoIterator<Item = Box<dyn bevy_reflect::Reflect>>;
 }
 
-impl AppInsertResources for App {
-    fn insert_resources<R: InsertResources>(&mut self, resources: R) -> &mut Self {
-        self.world.insert_resources(resources);
-        self
+#[cfg(feature = "reflect")]
+impl WorldReflectResources for World {
+    fn clone_resources_reflect<R: ReflectResources>(
+        &self,
+        registry: &bevy_reflect::TypeRegistry,
+    ) -> ReflectedResources {
+        R::clone_resources_reflect(self, registry)
+    }
+
+    fn apply_resources_reflect<I>(
+        &mut self,
+        registry: &bevy_reflect::TypeRegistry,
+        values: I,
+    ) -> Vec<String>
+    where
+        I: IntoIterator<Item = Box<dyn bevy_reflect::Reflect>>,
+    {
+        let mut unregistered = Vec::new();
+        for value in values {
+            match registry
+                .get_with_name(value.type_name())
+                .and_then(|registration| registration.data::<bevy_ecs::reflect::ReflectResource>())
+            {
+                Some(reflect_resource) => reflect_resource.apply_or_insert(self, &*value),
+                None => unregistered.push(value.type_name().to_owned()),
+            }
+        }
+        unregistered
     }
 }
 
-/// Extends [`Commands`] with `insert_resources`.
-pub trait CommandsInsertResources {
-    /// Pushes a [`Command`] to the queue for inserting a [`Resource`] in the [`World`] with a specific value.
+/// Resources that can be registered for reflection and initialized together.
+#[cfg(feature = "reflect")]
+pub trait RegisterResources: InitResources {
+    fn register_resources(registry: &mut bevy_reflect::TypeRegistry);
+}
+
+/// Extends [`App`] with `init_and_register_resources`.
+#[cfg(feature = "reflect")]
+pub trait AppRegisterResources {
+    /// Registers the resources in the [`AppTypeRegistry`], then initializes them.
     ///
-    /// This will overwrite any previous value of the same resource type.
+    /// This replaces a `register_type` call for each resource alongside `init_resources`.
+    /// The resources are registered first, so their [`FromWorld`] implementations can already find them in the registry.
     ///
-    /// See [`World::insert_resources`] for more details.
+    /// Requires the `reflect` feature.
+    ///
+    /// [`AppTypeRegistry`]: bevy_app::AppTypeRegistry
     ///
     /// # Example
     ///
     /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::AppTypeRegistry;
     /// # use bevy_ecs::prelude::*;
+    /// # use bevy_proto_resource_tuples::prelude::*;
+    /// # use bevy_reflect::Reflect;
     /// #
-    /// # #[derive(Resource)]
-    /// # struct PlayerScoreboard(u32);
+    /// # #[derive(Resource, Reflect, Default)]
+    /// # struct Score(u32);
     /// #
-    /// # #[derive(Resource)]
-    /// # struct EnemyScoreboard(u32);
+    /// # #[derive(Resource, Reflect, Default)]
+    /// # struct Lives(u32);
     /// #
-    /// # fn system(mut commands: Commands) {
-    /// commands.insert_resources((
-    ///     PlayerScoreboard(0),
-    ///     EnemyScoreboard(0),
-    /// ));
-    /// # }
-    /// # bevy_ecs::system::assert_is_system(system);
+    /// let mut app = App::new();
+    /// app.init_and_register_resources::<(Score, Lives)>();
+    ///
+    /// let registry = app.world.resource::<AppTypeRegistry>().read();
+    /// assert!(registry.get(std::any::TypeId::of::<Score>()).is_some());
+    /// assert!(registry.get(std::any::TypeId::of::<Lives>()).is_some());
+    /// # drop(registry);
+    /// assert_eq!(app.world.resource::<Lives>().0, 0);
     /// ```
-    fn insert_resources<R: InsertResources>(&mut self, resources: R);
+    fn init_and_register_resources<R: RegisterResources>(&mut self) -> &mut Self;
 }
 
-impl CommandsInsertResources for Commands<'_, '_> {
-    fn insert_resources<R: InsertResources>(&mut self, resources: R) {
-        self.add(InsertResourcesCommand { resources });
+#[cfg(feature = "reflect")]
+impl AppRegisterResources for App {
+    #[track_caller]
+    fn init_and_register_resources<R: RegisterResources>(&mut self) -> &mut Self {
+        R::register_resources(&mut self.world.resource::<bevy_app::AppTypeRegistry>().write());
+        self.init_resources::<R>()
     }
 }
 
-/// [`Command`] for `insert_resources`.
-pub struct InsertResourcesCommand<R: InsertResources> {
-    pub resources: R,
+/// Resources that can be serialized from the [`World`] together.
+#[cfg(feature = "serde")]
+pub trait SerializeResources: Send + Sync + 'static {
+    fn serialize_resources(world: &World) -> Result<String, SerializeResourcesError>;
 }
 
-impl<R: InsertResources> Command for InsertResourcesCommand<R> {
-    fn write(self, world: &mut World) {
-        world.insert_resources(self.resources);
+/// Error returned by [`serialize_resources`].
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum SerializeResourcesError {
+    /// The resource with this type name doesn't exist.
+    MissingResource(&'static str),
+    /// The resources couldn't be serialized.
+    Ron(ron::Error),
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for SerializeResourcesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingResource(type_name) => write!(f, "resource does not exist: {type_name}"),
+            Self::Ron(error) => write!(f, "failed to serialize resources: {error}"),
+        }
     }
 }
 
+#[cfg(feature = "serde")]
+impl std::error::Error for SerializeResourcesError {}
+
+#[cfg(feature = "serde")]
+impl From<ron::Error> for SerializeResourcesError {
+    fn from(error: ron::Error) -> Self {
+        Self::Ron(error)
+    }
+}
+
+/// Serializes the resources from the [`World`] into a RON string.
+///
+/// The resources are serialized as a tuple, so they can be restored with [`deserialize_resources`].
+///
+/// # Errors
+///
+/// Returns [`SerializeResourcesError::MissingResource`] naming the first resource that doesn't exist.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_proto_resource_tuples::{deserialize_resources, serialize_resources};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Resource, Serialize, Deserialize)]
+/// struct Score(u32);
+///
+/// #[derive(Resource, Serialize, Deserialize)]
+/// struct Lives(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(10));
+/// world.insert_resource(Lives(3));
+///
+/// let data = serialize_resources::<(Score, Lives)>(&world).unwrap();
+///
+/// let mut loaded = World::new();
+/// deserialize_resources::<(Score, Lives)>(&mut loaded, &data).unwrap();
+/// assert_eq!(loaded.resource::<Score>().0, 10);
+/// assert_eq!(loaded.resource::<Lives>().0, 3);
+/// ```
+#[cfg(feature = "serde")]
+pub fn serialize_resources<R: SerializeResources>(
+    world: &World,
+) -> Result<String, SerializeResourcesError> {
+    R::serialize_resources(world)
+}
+
+/// Deserializes the resources from a RON string and inserts them into the [`World`],
+/// returning the [`ComponentId`]s for them.
+///
+/// See [`serialize_resources`] for the counterpart.
+///
+/// # Errors
+///
+/// Returns an error if the data couldn't be deserialized, in which case nothing is inserted.
+#[cfg(feature = "serde")]
+pub fn deserialize_resources<R: InsertResources + serde::de::DeserializeOwned>(
+    world: &mut World,
+    data: &str,
+) -> Result<R::IDS, ron::error::SpannedError> {
+    let resources: R = ron::from_str(data)?;
+    Ok(world.insert_resources(resources))
+}
+
 bevy_proto_resource_tuples_macros::impl_resource_apis!();
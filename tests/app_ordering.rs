@@ -0,0 +1,67 @@
+//! Tests for when resources need to be added to an `App` to take effect.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource)]
+struct Lives(u32);
+
+#[derive(Resource)]
+struct Seen(Arc<AtomicBool>);
+
+#[test]
+fn resources_added_before_run_are_visible_to_systems() {
+    let seen = Arc::new(AtomicBool::new(false));
+
+    let mut app = App::new();
+    app.init_resources::<(Score,)>()
+        .insert_resources((Lives(3), Seen(seen.clone())))
+        .add_system(|score: Res<Score>, lives: Res<Lives>, seen: Res<Seen>| {
+            assert_eq!((score.0, lives.0), (0, 3));
+            seen.0.store(true, Ordering::Relaxed);
+        });
+    app.run();
+
+    assert!(seen.load(Ordering::Relaxed));
+}
+
+#[test]
+fn run_moves_resources_out_of_the_app() {
+    let mut app = App::new();
+    app.init_resources::<(Score,)>();
+    app.run();
+
+    // `run` replaced the app with an empty one,
+    // so anything added from here on is never seen by the systems that ran.
+    assert!(!app.world.contains_resource::<Score>());
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct ScoreSetup;
+
+#[test]
+fn startup_systems_after_the_set_see_the_resources() {
+    let seen = Arc::new(AtomicBool::new(false));
+
+    let mut app = App::new();
+    let check = |score: Option<Res<Score>>, seen: Res<Seen>| {
+        assert!(score.is_some());
+        seen.0.store(true, Ordering::Relaxed);
+    };
+    // Added before the initializing system, so only the ordering makes it run later.
+    app.insert_resources((Seen(seen.clone()),))
+        .add_startup_system(check.after(ScoreSetup))
+        .add_init_resources_system::<(Score,)>(ScoreSetup);
+    app.update();
+
+    assert!(seen.load(Ordering::Relaxed));
+}
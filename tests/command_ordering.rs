@@ -0,0 +1,150 @@
+//! Tests for the order resource commands are applied in relative to other commands.
+
+use bevy_ecs::{prelude::*, system::CommandQueue};
+use bevy_proto_resource_tuples::{prelude::*, resources};
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource, Default)]
+struct Lives(u32);
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+#[test]
+fn insert_resources_applies_in_queue_order() {
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands.add(|world: &mut World| {
+        assert!(!world.contains_resource::<Score>());
+        assert!(!world.contains_resource::<Lives>());
+        world.resource_mut::<Log>().0.push("before");
+    });
+    commands.insert_resources((Score(10), Lives(3)));
+    commands.add(|world: &mut World| {
+        let (score, lives) = world.get_resources::<(Score, Lives)>().unwrap();
+        assert_eq!((score.0, lives.0), (10, 3));
+        world.resource_mut::<Log>().0.push("after");
+    });
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Log>().0, ["before", "after"]);
+}
+
+#[test]
+fn init_resources_applies_in_queue_order() {
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands.add(|world: &mut World| {
+        assert!(!world.contains_resource::<Score>());
+        world.resource_mut::<Log>().0.push("before");
+    });
+    commands.init_resources::<(Score, Lives)>();
+    commands.add(|world: &mut World| {
+        assert!(world.contains_resource::<Score>());
+        assert!(world.contains_resource::<Lives>());
+        world.resource_mut::<Log>().0.push("after");
+    });
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Log>().0, ["before", "after"]);
+}
+
+#[test]
+fn later_commands_overwrite_inserted_resources() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands.insert_resources((Score(10), Lives(3)));
+    commands.insert_resource(Score(20));
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Score>().0, 20);
+    assert_eq!(world.resource::<Lives>().0, 3);
+}
+
+#[test]
+fn insert_if_absent_checks_when_applied() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands.insert_resource(Score(10));
+    commands.insert_resources_if_absent((Score(20), Lives(3)));
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Score>().0, 10);
+    assert_eq!(world.resource::<Lives>().0, 3);
+}
+
+#[test]
+fn after_doesnt_initialize_the_resources_again() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands
+        .init_resources::<(Score, Lives)>()
+        .after(|[score, lives]| {
+            assert_ne!(score, lives);
+        })
+        .commands()
+        .remove_resource::<Score>();
+    commands
+        .init_resources::<(Lives,)>()
+        .after(|_| {})
+        .after(|_| {});
+    queue.apply(&mut world);
+
+    assert!(!world.contains_resource::<Score>());
+    assert!(world.contains_resource::<Lives>());
+}
+
+#[test]
+fn resources_macro_queues_each_group_as_one_command() {
+    let mut world = World::new();
+    world.init_resource::<Log>();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    resources!(commands, insert: (Score(10), Lives(3)));
+    commands.add(|world: &mut World| {
+        let (score, lives) = world.get_resources::<(Score, Lives)>().unwrap();
+        assert_eq!((score.0, lives.0), (10, 3));
+        world.resource_mut::<Log>().0.push("between");
+    });
+    resources!(commands, insert: (Score(20),));
+    commands.add(|world: &mut World| {
+        assert_eq!(world.resource::<Score>().0, 20);
+        world.resource_mut::<Log>().0.push("after");
+    });
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Log>().0, ["between", "after"]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn resources_macro_checks_the_whole_group_at_once() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    resources!(commands, insert: (Lives(3), Score(1), Score(2)));
+
+    // A command per resource would insert each one without noticing the duplicate.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        queue.apply(&mut world);
+    }));
+    assert!(result.is_err());
+    assert!(!world.contains_resource::<Lives>());
+    assert!(!world.contains_resource::<Score>());
+}
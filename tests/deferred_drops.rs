@@ -0,0 +1,59 @@
+//! Tests for inserting resources while deferring the drops of overwritten values.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, Chain};
+
+/// Counts how many times a value was dropped.
+#[derive(Resource)]
+struct Tracked(Arc<AtomicUsize>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Resource)]
+struct Buffer(Vec<u8>);
+
+#[test]
+fn overwritten_values_drop_with_the_handle() {
+    let old_drops = Arc::new(AtomicUsize::new(0));
+    let new_drops = Arc::new(AtomicUsize::new(0));
+
+    let mut world = World::new();
+    world.insert_resources((Tracked(old_drops.clone()),));
+
+    let drops = world.insert_resources_defer_drop((Tracked(new_drops.clone()), Buffer(vec![1])));
+    assert_eq!(drops.len(), 1);
+    assert_eq!(old_drops.load(Ordering::SeqCst), 0);
+
+    drop(drops);
+    assert_eq!(old_drops.load(Ordering::SeqCst), 1);
+    assert_eq!(new_drops.load(Ordering::SeqCst), 0);
+    assert_eq!(world.resource::<Buffer>().0, [1]);
+}
+
+#[test]
+fn new_resources_add_nothing() {
+    let mut world = World::new();
+    let drops = world.insert_resources_defer_drop(Chain((Buffer(vec![]),), ()));
+    assert!(drops.is_empty());
+}
+
+#[test]
+fn handle_can_drop_on_another_thread() {
+    let old_drops = Arc::new(AtomicUsize::new(0));
+
+    let mut world = World::new();
+    world.insert_resources((Tracked(old_drops.clone()),));
+    let drops = world.insert_resources_defer_drop((Tracked(Arc::new(AtomicUsize::new(0))),));
+
+    std::thread::spawn(move || drop(drops)).join().unwrap();
+    assert_eq!(old_drops.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,61 @@
+//! Tests for the resource counts kept by `ResourceTupleDiagnosticsPlugin`.
+#![cfg(feature = "diagnostic")]
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, OrDefault, ResourceTupleCounts};
+
+#[derive(Resource, Default)]
+struct Score;
+
+#[derive(Resource, Default)]
+struct Lives;
+
+fn world() -> World {
+    let mut world = World::new();
+    world.init_resource::<ResourceTupleCounts>();
+    world
+}
+
+#[test]
+fn existing_resources_arent_counted_as_initialized() {
+    let mut world = world();
+    world.init_resources::<(Score,)>();
+    world.init_resources::<(Score, Lives)>();
+    world.ensure_resources::<(Score, Lives)>();
+
+    assert_eq!(world.resource::<ResourceTupleCounts>().initialized, 2);
+}
+
+#[test]
+fn other_entry_points_are_counted() {
+    let mut world = world();
+    world.init_resources_report::<(Score,)>();
+    world.insert_or_init_resources((OrDefault::Value(Lives),));
+    world.insert_resources_if_absent((Score, Lives));
+
+    let counts = *world.resource::<ResourceTupleCounts>();
+    assert_eq!(counts.initialized, 1);
+    assert_eq!(counts.inserted, 1);
+}
+
+#[test]
+fn repeated_resources_are_counted_once() {
+    let mut world = world();
+    world.init_resources::<[Score; 2]>();
+
+    assert_eq!(world.resource::<ResourceTupleCounts>().initialized, 1);
+}
+
+#[test]
+fn written_back_resources_are_counted() {
+    let mut world = world();
+    world.update_resources::<(Score,)>(|score| score);
+    world.insert_resources((Score,));
+    world.update_resources::<(Score,)>(|score| score);
+    world.swap_resources((Score,));
+    world.reset_resources::<(Lives,)>();
+
+    let counts = *world.resource::<ResourceTupleCounts>();
+    assert_eq!(counts.initialized, 0);
+    assert_eq!(counts.inserted, 4);
+}
@@ -0,0 +1,37 @@
+//! Tests for resources whose destructors panic, with the `catch_drop_panics` feature.
+#![cfg(feature = "catch_drop_panics")]
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource, Default)]
+struct Volatile(u32);
+
+impl Drop for Volatile {
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            panic!("volatile resource dropped while non-zero");
+        }
+    }
+}
+
+#[test]
+fn reset_survives_panicking_drop() {
+    let mut world = World::new();
+    world.insert_resource(Volatile(1));
+
+    let existed = world.reset_resources::<(Volatile,)>();
+
+    assert_eq!(existed.as_ref(), &[true]);
+    assert_eq!(world.resource::<Volatile>().0, 0);
+}
+
+#[test]
+fn skipped_insert_survives_panicking_drop() {
+    let mut world = World::new();
+    world.init_resource::<Volatile>();
+
+    world.insert_resources_if_absent((Volatile(1),));
+
+    assert_eq!(world.resource::<Volatile>().0, 0);
+}
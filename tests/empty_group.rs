@@ -0,0 +1,54 @@
+//! Tests for the empty resource group `()`.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, Chain, InitResources};
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+fn init_group<R: InitResources>(world: &mut World) -> R::IDS {
+    world.init_resources::<R>()
+}
+
+#[test]
+fn init_empty_group_does_nothing() {
+    let mut world = World::new();
+    let before = world.components().len();
+
+    let ids: [_; 0] = world.init_resources::<()>();
+
+    assert!(ids.is_empty());
+    assert_eq!(world.components().len(), before);
+    assert_eq!(init_group::<()>(&mut world), []);
+}
+
+#[test]
+fn insert_empty_group_does_nothing() {
+    let mut world = World::new();
+    world.insert_resource(Score(1));
+
+    let ids: [_; 0] = world.insert_resources(());
+
+    assert!(ids.is_empty());
+    assert_eq!(world.resource::<Score>().0, 1);
+    assert_eq!(world.try_insert_resources(()).unwrap(), []);
+}
+
+#[test]
+fn empty_group_chains() {
+    let mut world = World::new();
+
+    let ([], [score]) = world.insert_resources(Chain((), (Score(2),)));
+
+    assert_eq!(world.components().resource_id::<Score>(), Some(score));
+}
+
+#[test]
+fn empty_array_map_does_nothing() {
+    let mut world = World::new();
+
+    let ids = world.init_resources_map::<[Score; 0]>();
+
+    assert!(ids.is_empty());
+    assert!(!world.contains_resource::<Score>());
+}
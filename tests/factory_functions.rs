@@ -0,0 +1,79 @@
+//! Tests for inserting groups of resources returned from factory functions.
+
+use bevy::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, ChainResources, InsertResources};
+
+#[derive(Resource)]
+struct Volume(f32);
+
+#[derive(Resource)]
+struct Muted(bool);
+
+#[derive(Resource, Default)]
+struct Settings<T: Send + Sync + 'static>(T);
+
+fn make_audio_group() -> impl InsertResources {
+    (Volume(0.5), Muted(false))
+}
+
+fn make_settings<T: Send + Sync + Default + 'static>(value: T) -> (Settings<T>, Muted) {
+    (Settings(value), Muted(true))
+}
+
+fn make_generic<A: Resource, B: Resource>(a: A, b: B) -> impl InsertResources {
+    (a, b)
+}
+
+fn make_nested() -> impl InsertResources {
+    make_audio_group().chain((Settings(1u8),))
+}
+
+#[test]
+fn app_insert_from_impl_trait() {
+    let mut app = App::new();
+    app.insert_resources(make_audio_group());
+
+    assert_eq!(app.world.resource::<Volume>().0, 0.5);
+    assert!(!app.world.resource::<Muted>().0);
+}
+
+#[test]
+fn world_insert_from_generic_return() {
+    let mut world = World::new();
+    let ids = world.insert_resources(make_settings(7u32));
+
+    assert_eq!(world.resource::<Settings<u32>>().0, 7);
+    assert!(world.resource::<Muted>().0);
+    assert_eq!(
+        ids[0],
+        world.components().resource_id::<Settings<u32>>().unwrap()
+    );
+}
+
+#[test]
+fn insert_from_generic_impl_trait() {
+    let mut world = World::new();
+    world.insert_resources(make_generic(Volume(1.0), Settings("loud")));
+
+    assert_eq!(world.resource::<Volume>().0, 1.0);
+    assert_eq!(world.resource::<Settings<&str>>().0, "loud");
+}
+
+#[test]
+fn insert_nested_factory() {
+    let mut world = World::new();
+    world.insert_resources(make_nested());
+
+    assert_eq!(world.resource::<Volume>().0, 0.5);
+    assert_eq!(world.resource::<Settings<u8>>().0, 1);
+}
+
+#[test]
+fn commands_insert_from_factory() {
+    let mut world = World::new();
+    let mut queue = bevy::ecs::system::CommandQueue::default();
+    Commands::new(&mut queue, &world).insert_resources(make_audio_group());
+    queue.apply(&mut world);
+
+    assert_eq!(world.resource::<Volume>().0, 0.5);
+}
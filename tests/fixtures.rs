@@ -0,0 +1,83 @@
+//! Tests for setting up resources per test case with `ResourceGroupFixture`.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{ResourceGroupFixture, ResourceInitLog};
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource, Default)]
+struct Lives(u32);
+
+#[derive(Resource, Default)]
+struct Level;
+
+#[test]
+fn removes_only_created_resources() {
+    let mut world = World::new();
+    world.insert_resource(Lives(3));
+
+    {
+        let mut fixture = ResourceGroupFixture::<(Score, Lives, Level)>::new(&mut world);
+        assert_eq!(fixture.created(), &[true, false, true]);
+        fixture.world().resource_mut::<Score>().0 = 100;
+    }
+
+    assert!(!world.contains_resource::<Score>());
+    assert_eq!(world.resource::<Lives>().0, 3);
+    assert!(!world.contains_resource::<Level>());
+}
+
+#[test]
+fn repeated_fixtures_start_clean() {
+    let mut world = World::new();
+
+    for _ in 0..3 {
+        let mut fixture = ResourceGroupFixture::<(Score, Lives)>::new(&mut world);
+        let world = fixture.world();
+        assert_eq!(world.resource::<Score>().0, 0);
+        world.resource_mut::<Score>().0 += 1;
+    }
+
+    assert!(!world.contains_resource::<Score>());
+    assert!(!world.contains_resource::<Lives>());
+}
+
+#[test]
+fn nested_fixtures_leave_outer_resources() {
+    let mut world = World::new();
+    let mut outer = ResourceGroupFixture::<(Score,)>::new(&mut world);
+
+    {
+        let inner = ResourceGroupFixture::<(Score, Level)>::new(outer.world());
+        assert_eq!(inner.created(), &[false, true]);
+    }
+
+    assert!(outer.world().contains_resource::<Score>());
+    assert!(!outer.world().contains_resource::<Level>());
+    drop(outer);
+    assert!(!world.contains_resource::<Score>());
+}
+
+#[test]
+fn cleans_up_while_unwinding() {
+    let mut world = World::new();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _fixture = ResourceGroupFixture::<(Score, Lives)>::new(&mut world);
+        panic!("test case failed");
+    }));
+
+    assert!(result.is_err());
+    assert!(!world.contains_resource::<Score>());
+    assert!(!world.contains_resource::<Lives>());
+}
+
+#[test]
+fn initializes_the_group_once() {
+    let mut world = World::new();
+    world.init_resource::<ResourceInitLog>();
+
+    let mut fixture = ResourceGroupFixture::<(Score, Lives)>::new(&mut world);
+    assert_eq!(fixture.world().resource::<ResourceInitLog>().0.len(), 2);
+}
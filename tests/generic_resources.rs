@@ -0,0 +1,220 @@
+//! Regression tests for resources with generic and const generic parameters in tuples.
+
+use std::marker::PhantomData;
+
+use bevy_ecs::{prelude::*, system::CommandQueue};
+use bevy_proto_resource_tuples::{prelude::*, InitResources};
+
+#[derive(Resource)]
+struct Buffer<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+#[derive(Resource, Default)]
+struct Wrapper<T: Send + Sync + 'static>(T);
+
+#[test]
+fn init_const_generic_resources() {
+    let mut world = World::new();
+    let [four, eight] = world.init_resources::<(Buffer<4>, Buffer<8>)>();
+
+    assert_ne!(four, eight);
+    assert_eq!(world.components().resource_id::<Buffer<4>>(), Some(four));
+    assert_eq!(world.components().resource_id::<Buffer<8>>(), Some(eight));
+    assert_eq!(world.resource::<Buffer<8>>().0.len(), 8);
+}
+
+#[test]
+fn insert_const_generic_resources() {
+    let mut world = World::new();
+    world.insert_resources((Buffer([1; 2]), Buffer([2; 3])));
+
+    assert_eq!(world.resource::<Buffer<2>>().0, [1; 2]);
+    assert_eq!(world.resource::<Buffer<3>>().0, [2; 3]);
+}
+
+#[test]
+fn generic_resources_are_distinct() {
+    let mut world = World::new();
+    let [a, b] = world.init_resources::<(Wrapper<u32>, Wrapper<f32>)>();
+    assert_ne!(a, b);
+
+    world.insert_resources((Wrapper(1u32), Wrapper(2.0f32)));
+    assert_eq!(world.resource::<Wrapper<u32>>().0, 1);
+    assert_eq!(world.resource::<Wrapper<f32>>().0, 2.0);
+
+    world.remove_resources::<(Wrapper<u32>,)>();
+    assert!(!world.contains_resource::<Wrapper<u32>>());
+    assert!(world.contains_resource::<Wrapper<f32>>());
+}
+
+#[test]
+fn get_and_take_generic_resources() {
+    let mut world = World::new();
+    world.insert_resources((Buffer([7; 1]), Wrapper(Buffer([9; 2]))));
+
+    let (buffer, wrapper) = world
+        .get_resources::<(Buffer<1>, Wrapper<Buffer<2>>)>()
+        .unwrap();
+    assert_eq!(buffer.0, [7]);
+    assert_eq!(wrapper.0 .0, [9; 2]);
+
+    let (buffer, wrapper) = world
+        .take_resources::<(Buffer<1>, Wrapper<Buffer<2>>)>()
+        .unwrap();
+    assert_eq!((buffer.0, wrapper.0 .0), ([7], [9; 2]));
+    assert!(!world.contains_any_resources::<(Buffer<1>, Wrapper<Buffer<2>>)>());
+}
+
+#[test]
+fn type_names_include_generic_parameters() {
+    let [four, wrapper] = <(Buffer<4>, Wrapper<u8>) as InitResources>::type_names();
+
+    assert!(four.ends_with("Buffer<4>"));
+    assert!(wrapper.ends_with("Wrapper<u8>"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "appears more than once")]
+fn same_const_generic_resource_twice_panics() {
+    let mut world = World::new();
+    world.init_resources::<(Buffer<4>, Buffer<4>)>();
+}
+
+#[derive(ResourceGroup)]
+struct Buffers<const N: usize> {
+    small: Buffer<N>,
+    large: Buffer<16>,
+}
+
+#[test]
+fn derive_const_generic_group() {
+    let mut world = World::new();
+    let ids = world.init_resources::<Buffers<2>>();
+
+    assert_eq!(
+        world.components().resource_id::<Buffer<2>>(),
+        Some(ids.small)
+    );
+    assert_eq!(
+        world.components().resource_id::<Buffer<16>>(),
+        Some(ids.large)
+    );
+
+    world.insert_resources(Buffers {
+        small: Buffer([3; 2]),
+        large: Buffer([4; 16]),
+    });
+    assert_eq!(world.resource::<Buffer<2>>().0, [3; 2]);
+}
+
+type Pair<T> = (Wrapper<T>, Buffer<2>);
+
+type Nested<T, const N: usize> = (Wrapper<T>, Wrapper<Buffer<N>>, Buffer<N>);
+
+#[test]
+fn init_generic_alias() {
+    let mut world = World::new();
+    let [wrapper, buffer] = world.init_resources::<Pair<i32>>();
+
+    assert_eq!(
+        world.components().resource_id::<Wrapper<i32>>(),
+        Some(wrapper)
+    );
+    assert_eq!(world.components().resource_id::<Buffer<2>>(), Some(buffer));
+    assert!(!world.contains_resource::<Wrapper<u32>>());
+}
+
+#[test]
+fn insert_generic_alias() {
+    let mut world = World::new();
+    let resources: Pair<&'static str> = (Wrapper("pair"), Buffer([1; 2]));
+    world.insert_resources(resources);
+
+    assert_eq!(world.resource::<Wrapper<&str>>().0, "pair");
+}
+
+#[test]
+fn generic_alias_through_app() {
+    let mut app = bevy_app::App::new();
+    app.init_resources::<Nested<u8, 3>>()
+        .insert_resources::<Pair<u8>>((Wrapper(5), Buffer([0; 2])));
+
+    assert!(app.world.contains_all_resources::<Nested<u8, 3>>());
+    assert_eq!(app.world.resource::<Wrapper<u8>>().0, 5);
+}
+
+fn init_in_generic_fn<T: Send + Sync + Default + 'static>(world: &mut World) -> usize {
+    world.init_resources::<Pair<T>>().len()
+}
+
+#[test]
+fn generic_alias_in_generic_fn() {
+    let mut world = World::new();
+    assert_eq!(init_in_generic_fn::<String>(&mut world), 2);
+    assert!(world.contains_resource::<Wrapper<String>>());
+}
+
+/// A zero-sized marker resource.
+///
+/// `Default` is implemented by hand, since deriving it would require `T: Default`.
+#[derive(Resource)]
+struct Active<T: Send + Sync + 'static>(PhantomData<T>);
+
+impl<T: Send + Sync + 'static> Default for Active<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Marker types that only exist at the type level.
+struct Red;
+struct Blue;
+enum Green {}
+
+#[test]
+fn init_phantom_markers() {
+    let mut world = World::new();
+    let [red, blue, green] = world.init_resources::<(Active<Red>, Active<Blue>, Active<Green>)>();
+
+    assert_ne!(red, blue);
+    assert_ne!(blue, green);
+    assert_eq!(world.components().resource_id::<Active<Red>>(), Some(red));
+    assert_eq!(
+        world.components().resource_id::<Active<Green>>(),
+        Some(green)
+    );
+}
+
+#[test]
+fn insert_and_remove_phantom_markers() {
+    let mut world = World::new();
+    world.insert_resources((Active::<Red>(PhantomData), Active::<Blue>(PhantomData)));
+
+    assert!(world.contains_all_resources::<(Active<Red>, Active<Blue>)>());
+    assert!(!world.contains_resource::<Active<Green>>());
+
+    world.remove_resources::<(Active<Red>,)>();
+    assert_eq!(
+        world.contains_resources::<(Active<Red>, Active<Blue>)>(),
+        [false, true]
+    );
+}
+
+#[test]
+fn phantom_markers_through_commands() {
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+
+    let mut commands = Commands::new(&mut queue, &world);
+    commands.init_resources::<(Active<Red>, Active<Green>)>();
+    commands.insert_resources((Active::<Blue>::default(),));
+    queue.apply(&mut world);
+
+    assert!(world.contains_all_resources::<(Active<Red>, Active<Blue>, Active<Green>)>());
+}
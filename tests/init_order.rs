@@ -0,0 +1,72 @@
+//! Tests for the order tuple elements are initialized in, which `FromWorld` implementations may depend on.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+/// Records the order `FromWorld` implementations ran in.
+#[derive(Resource, Default)]
+struct Order(Vec<&'static str>);
+
+macro_rules! ordered_resources {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Resource)]
+            struct $name(usize);
+
+            impl FromWorld for $name {
+                fn from_world(world: &mut World) -> Self {
+                    let mut order = world.get_resource_or_insert_with(Order::default);
+                    order.0.push(stringify!($name));
+                    $name(order.0.len())
+                }
+            }
+        )*
+    };
+}
+
+ordered_resources!(A, B, C, D);
+
+#[test]
+fn init_resources_runs_left_to_right() {
+    let mut world = World::new();
+    world.init_resources::<(A, B, C, D)>();
+
+    assert_eq!(world.resource::<Order>().0, ["A", "B", "C", "D"]);
+    let (a, b, c, d) = world.get_resources::<(A, B, C, D)>().unwrap();
+    assert_eq!([a.0, b.0, c.0, d.0], [1, 2, 3, 4]);
+}
+
+#[test]
+fn init_resources_order_follows_the_tuple() {
+    let mut world = World::new();
+    world.init_resources::<(D, B, A, C)>();
+
+    assert_eq!(world.resource::<Order>().0, ["D", "B", "A", "C"]);
+}
+
+#[test]
+fn init_resources_variants_run_left_to_right() {
+    let mut world = World::new();
+    world.init_resources_verbose::<(A, B, C, D)>();
+    assert_eq!(world.resource::<Order>().0, ["A", "B", "C", "D"]);
+
+    let mut world = World::new();
+    world.init_resources_report::<(C, A, D, B)>();
+    assert_eq!(world.resource::<Order>().0, ["C", "A", "D", "B"]);
+}
+
+#[test]
+fn later_resources_can_read_earlier_ones() {
+    #[derive(Resource)]
+    struct Dependent(usize);
+
+    impl FromWorld for Dependent {
+        fn from_world(world: &mut World) -> Self {
+            Dependent(world.resource::<C>().0 * 10)
+        }
+    }
+
+    let mut world = World::new();
+    world.init_resources::<(A, B, C, Dependent)>();
+    assert_eq!(world.resource::<Dependent>().0, 30);
+}
@@ -0,0 +1,87 @@
+//! Tests for `App::init_resources_when_loaded`, with the `asset` feature.
+#![cfg(feature = "asset")]
+
+use std::time::Duration;
+
+use bevy::{
+    asset::{AssetPlugin, HandleId, LoadState},
+    prelude::*,
+    reflect::{self as bevy_reflect, TypeUuid},
+};
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(TypeUuid)]
+#[uuid = "5b0e7c1a-2d4f-4e8b-a6c3-9f1d0e2b7a45"]
+struct Tuning {
+    speed: f32,
+}
+
+#[derive(Resource)]
+struct TuningHandle(Handle<Tuning>);
+
+#[derive(Resource)]
+struct Speed(f32);
+
+impl FromWorld for Speed {
+    fn from_world(world: &mut World) -> Self {
+        let handle = &world.resource::<TuningHandle>().0;
+        let tuning = world.resource::<Assets<Tuning>>().get(handle).unwrap();
+        Speed(tuning.speed)
+    }
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(AssetPlugin::default())
+        .add_asset::<Tuning>();
+    app
+}
+
+#[test]
+fn waits_for_asset() {
+    let mut app = app();
+    let handle = app
+        .world
+        .resource::<Assets<Tuning>>()
+        .get_handle(HandleId::random::<Tuning>());
+    app.insert_resource(TuningHandle(handle.clone()))
+        .init_resources_when_loaded::<(Speed,), _>(handle.clone());
+
+    app.update();
+    app.update();
+    assert!(!app.world.contains_resource::<Speed>());
+
+    app.world
+        .resource_mut::<Assets<Tuning>>()
+        .set_untracked(handle, Tuning { speed: 3.0 });
+    app.update();
+    assert_eq!(app.world.resource::<Speed>().0, 3.0);
+}
+
+#[test]
+fn failed_asset_skips_init() {
+    let mut app = app();
+    let handle: Handle<Tuning> = app
+        .world
+        .resource::<AssetServer>()
+        .load("tuning.not-a-real-extension");
+    app.insert_resource(TuningHandle(handle.clone()))
+        .init_resources_when_loaded::<(Speed,), _>(handle.clone());
+
+    // Loading happens on the IO task pool, so wait for it to fail.
+    for _ in 0..100 {
+        if app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Failed {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(
+        app.world.resource::<AssetServer>().get_load_state(&handle),
+        LoadState::Failed
+    );
+
+    app.update();
+    app.update();
+    assert!(!app.world.contains_resource::<Speed>());
+}
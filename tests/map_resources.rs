@@ -0,0 +1,46 @@
+//! Tests for transforming resources one closure at a time with `map_resources`.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource)]
+struct Version(u32);
+
+#[derive(Resource)]
+struct Volume(f32);
+
+#[derive(Resource)]
+struct Brightness(f32);
+
+#[test]
+fn panicking_closure_keeps_earlier_results() {
+    let mut world = World::new();
+    world.insert_resources((Version(1), Volume(50.0), Brightness(80.0)));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.map_resources::<(Version, Volume, Brightness), _>((
+            |version: Version| Version(version.0 + 1),
+            |_: Volume| -> Volume { panic!("migration failed") },
+            |brightness: Brightness| Brightness(brightness.0 / 100.0),
+        ));
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(world.resource::<Version>().0, 2);
+    assert!(!world.contains_resource::<Volume>());
+    assert_eq!(world.resource::<Brightness>().0, 80.0);
+}
+
+#[test]
+fn missing_resources_skip_only_their_closure() {
+    let mut world = World::new();
+    world.insert_resource(Volume(50.0));
+
+    let mapped = world.map_resources::<(Version, Volume), _>((
+        |_: Version| -> Version { unreachable!() },
+        |volume: Volume| Volume(volume.0 * 2.0),
+    ));
+
+    assert_eq!(mapped, [false, true]);
+    assert_eq!(world.resource::<Volume>().0, 100.0);
+}
@@ -0,0 +1,114 @@
+//! Tests for using the tuple APIs from inside `FromWorld` implementations.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource, Default)]
+struct Gravity(f32);
+
+#[derive(Resource, Default)]
+struct Wind;
+
+#[derive(Resource)]
+struct Seed(u64);
+
+/// A composite resource that makes sure its helper resources exist.
+#[derive(Resource)]
+struct Physics {
+    gravity: f32,
+    seed: u64,
+}
+
+impl FromWorld for Physics {
+    fn from_world(world: &mut World) -> Self {
+        world.init_resources::<(Gravity, Wind)>();
+        if !world.contains_resource::<Seed>() {
+            world.insert_resources((Seed(7),));
+        }
+
+        Physics {
+            gravity: world.resource::<Gravity>().0,
+            seed: world.resource::<Seed>().0,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct Simulation(u64);
+
+impl FromWorld for Simulation {
+    fn from_world(world: &mut World) -> Self {
+        let [_physics] = world.init_resources::<(Physics,)>();
+        Simulation(world.resource::<Physics>().seed)
+    }
+}
+
+#[test]
+fn init_helpers_from_from_world() {
+    let mut world = World::new();
+    world.init_resources::<(Physics,)>();
+
+    assert!(world.contains_all_resources::<(Physics, Gravity, Wind, Seed)>());
+    assert_eq!(world.resource::<Physics>().seed, 7);
+}
+
+#[test]
+fn existing_helpers_are_kept() {
+    let mut world = World::new();
+    world.insert_resources((Gravity(9.8), Seed(42)));
+    world.init_resources::<(Physics,)>();
+
+    let physics = world.resource::<Physics>();
+    assert_eq!((physics.gravity, physics.seed), (9.8, 42));
+}
+
+#[test]
+fn nested_init_within_a_tuple() {
+    let mut world = World::new();
+    let [simulation, physics, wind] = world.init_resources::<(Simulation, Physics, Wind)>();
+
+    assert_eq!(world.resource::<Simulation>().0, 7);
+    assert_eq!(
+        world.components().resource_id::<Simulation>(),
+        Some(simulation)
+    );
+    assert_eq!(world.components().resource_id::<Physics>(), Some(physics));
+    assert_eq!(world.components().resource_id::<Wind>(), Some(wind));
+}
+
+#[test]
+fn nested_init_through_commands() {
+    let mut world = World::new();
+    let mut queue = bevy_ecs::system::CommandQueue::default();
+    Commands::new(&mut queue, &world).init_resources::<(Simulation,)>();
+    queue.apply(&mut world);
+
+    assert!(world.contains_all_resources::<(Simulation, Physics, Gravity, Wind, Seed)>());
+}
+
+/// Records whether the id of a later resource in the tuple was registered when it was initialized.
+#[derive(Resource)]
+struct SeesWind(bool);
+
+impl FromWorld for SeesWind {
+    fn from_world(world: &mut World) -> Self {
+        SeesWind(world.components().resource_id::<Wind>().is_some())
+    }
+}
+
+#[test]
+fn two_phase_registers_sibling_ids_first() {
+    let mut world = World::new();
+    let [_, wind] = world.init_resources_two_phase::<(SeesWind, Wind)>();
+
+    assert!(world.resource::<SeesWind>().0);
+    assert_eq!(world.components().resource_id::<Wind>(), Some(wind));
+}
+
+#[test]
+fn default_init_registers_ids_in_order() {
+    let mut world = World::new();
+    world.init_resources::<(SeesWind, Wind)>();
+
+    assert!(!world.resource::<SeesWind>().0);
+}
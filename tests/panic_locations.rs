@@ -0,0 +1,91 @@
+//! Tests that duplicate-resource panics point at the caller rather than into this crate.
+#![cfg(debug_assertions)]
+
+use std::{cell::Cell, panic, sync::Once};
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, Chain, Fill, OrDefault};
+
+#[derive(Resource, Default)]
+struct Score;
+
+#[derive(Resource, Default)]
+struct Lives;
+
+#[derive(ResourceGroup)]
+struct Duplicated {
+    first: Score,
+    second: Score,
+}
+
+thread_local! {
+    static PANIC_LINE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// Runs `f`, which should panic, and returns the line the panic was reported at in this file.
+fn panic_line(f: impl FnOnce(&mut World)) -> Option<u32> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        panic::set_hook(Box::new(|info| {
+            let location = info.location().unwrap();
+            if location.file() == file!() {
+                PANIC_LINE.with(|line| line.set(Some(location.line())));
+            }
+        }));
+    });
+
+    PANIC_LINE.with(|line| line.set(None));
+    let mut world = World::new();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| f(&mut world)));
+    assert!(result.is_err());
+    PANIC_LINE.with(Cell::get)
+}
+
+#[test]
+fn insert_resources_if_absent_reports_the_caller() {
+    let line = line!() + 2;
+    let location = panic_line(|world| {
+        world.insert_resources_if_absent((Score, Score));
+    });
+    assert_eq!(location, Some(line));
+
+    let line = line!() + 2;
+    let location = panic_line(|world| {
+        world.insert_resources_if_absent(Chain((Score,), (Lives, Lives)));
+    });
+    assert_eq!(location, Some(line));
+
+    let line = line!() + 2;
+    let location = panic_line(|world| {
+        world.insert_resources_if_absent(Duplicated {
+            first: Score,
+            second: Score,
+        });
+    });
+    assert_eq!(location, Some(line));
+}
+
+#[test]
+fn insert_or_init_resources_reports_the_caller() {
+    let line = line!() + 2;
+    let location = panic_line(|world| {
+        world.insert_or_init_resources((OrDefault::Value(Score), OrDefault::<Score>::Default));
+    });
+    assert_eq!(location, Some(line));
+
+    let line = line!() + 2;
+    let location = panic_line(|_| {
+        App::new().insert_or_init_resources((OrDefault::Value(Score), OrDefault::<Score>::Default));
+    });
+    assert_eq!(location, Some(line));
+}
+
+#[test]
+fn insert_filled_resources_reports_the_caller() {
+    let line = line!() + 2;
+    let location = panic_line(|world| {
+        world.insert_filled_resources((Score, Fill::<Score>::new()));
+    });
+    assert_eq!(location, Some(line));
+}
@@ -0,0 +1,130 @@
+//! Tests for recording resource setup in a `ResourceInitLog`.
+
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, Chain, ResourceGroupBuilder, ResourceInitLog};
+
+#[derive(Resource, Default, Clone)]
+struct Score;
+
+#[derive(Resource, Default, Clone)]
+struct Lives;
+
+#[derive(Resource)]
+struct Level;
+
+#[test]
+fn nothing_recorded_without_log() {
+    let mut world = World::new();
+    world.init_resources::<(Score, Lives)>();
+    assert!(!world.contains_resource::<ResourceInitLog>());
+}
+
+#[test]
+fn records_groups_in_order() {
+    let mut app = App::new();
+    app.enable_resource_audit()
+        .init_resources::<Chain<(Score,), (Lives,)>>()
+        .insert_resources((Level,));
+
+    let log = app.world.resource::<ResourceInitLog>();
+    let types: Vec<_> = log.0.iter().map(|&(id, _, _)| id).collect();
+    assert_eq!(
+        types,
+        [
+            TypeId::of::<Score>(),
+            TypeId::of::<Lives>(),
+            TypeId::of::<Level>()
+        ]
+    );
+}
+
+#[test]
+fn dump_is_sorted_by_tick() {
+    let mut log = ResourceInitLog::default();
+    log.0.push((TypeId::of::<Level>(), "Level", 7));
+    log.0.push((TypeId::of::<Score>(), "Score", 2));
+    log.0.push((TypeId::of::<Lives>(), "Lives", 2));
+
+    let names: Vec<_> = log
+        .sorted_by_tick()
+        .into_iter()
+        .map(|(_, name, _)| name)
+        .collect();
+    assert_eq!(names, ["Score", "Lives", "Level"]);
+
+    let dump = log.dump();
+    let lines: Vec<_> = dump.lines().map(str::trim).collect();
+    assert_eq!(lines, ["2 Score", "2 Lives", "7 Level"]);
+}
+
+#[test]
+fn commands_record_later_ticks() {
+    let mut app = App::new();
+    app.enable_resource_audit()
+        .init_resources::<(Score,)>()
+        .add_system(|mut commands: Commands| {
+            commands.insert_resources((Level,));
+        });
+    app.update();
+
+    let entries = app.world.resource::<ResourceInitLog>().sorted_by_tick();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].0, TypeId::of::<Level>());
+    assert!(entries[1].2 > entries[0].2);
+}
+
+#[test]
+fn records_other_entry_points() {
+    let mut world = World::new();
+    world.init_resource::<ResourceInitLog>();
+
+    world.init_resources_report::<(Score,)>();
+    world.insert_optional_resources((Some(Level), None::<Lives>));
+    ResourceGroupBuilder::new()
+        .init::<Lives>()
+        .apply(&mut world);
+    world.update_resources::<(Score,)>(|score| score);
+    world.swap_resources((Lives,));
+    world.resources_scope(|_, _: &mut (Level,)| {});
+    world.with_resources((Level,), |_| {});
+    world.reset_resources::<(Score,)>();
+
+    let mut other = World::new();
+    other.insert_resource(Lives);
+    other.move_resources::<(Lives,)>(&mut world);
+    other.insert_resource(Score);
+    other.clone_resources_into::<(Score, Lives)>(&mut world);
+    world.extend_resources_overwriting::<(Score,)>(&other);
+    world.extend_resources_if_absent::<(Score,)>(&other);
+
+    let names: Vec<_> = world
+        .resource::<ResourceInitLog>()
+        .0
+        .iter()
+        .map(|&(_, name, _)| name)
+        .collect();
+    assert_eq!(
+        names,
+        [
+            std::any::type_name::<Score>(),
+            std::any::type_name::<Level>(),
+            std::any::type_name::<Lives>(),
+            // update, swap and scope write the groups back
+            std::any::type_name::<Score>(),
+            std::any::type_name::<Lives>(),
+            std::any::type_name::<Level>(),
+            // with_resources inserts the group, then restores the previous value
+            std::any::type_name::<Level>(),
+            std::any::type_name::<Level>(),
+            // reset and move
+            std::any::type_name::<Score>(),
+            std::any::type_name::<Lives>(),
+            // clone only copies what exists in the source, then extend overwriting and if absent
+            std::any::type_name::<Score>(),
+            std::any::type_name::<Score>(),
+            std::any::type_name::<Score>()
+        ]
+    );
+}
@@ -0,0 +1,101 @@
+//! Tests for building groups of resources at runtime.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::ResourceGroupBuilder;
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+#[derive(Resource)]
+struct First;
+
+impl FromWorld for First {
+    fn from_world(world: &mut World) -> Self {
+        world.resource_mut::<Log>().0.push("first");
+        First
+    }
+}
+
+#[derive(Resource)]
+struct Second(u32);
+
+#[derive(Resource)]
+struct Third;
+
+impl FromWorld for Third {
+    fn from_world(world: &mut World) -> Self {
+        // `Second` was inserted by the previous operation.
+        let second = world.resource::<Second>().0;
+        assert_eq!(second, 2);
+        world.resource_mut::<Log>().0.push("third");
+        Third
+    }
+}
+
+#[test]
+fn applies_operations_in_order() {
+    let mut world = World::new();
+
+    let ids = ResourceGroupBuilder::new()
+        .init::<Log>()
+        .init::<First>()
+        .insert(Second(2))
+        .init::<Third>()
+        .apply(&mut world);
+
+    let components = world.components();
+    assert_eq!(
+        ids,
+        [
+            components.resource_id::<Log>().unwrap(),
+            components.resource_id::<First>().unwrap(),
+            components.resource_id::<Second>().unwrap(),
+            components.resource_id::<Third>().unwrap(),
+        ]
+    );
+    assert_eq!(world.resource::<Log>().0, ["first", "third"]);
+}
+
+#[test]
+fn init_keeps_and_insert_overwrites() {
+    let mut world = World::new();
+    world.insert_resource(Log(vec!["existing"]));
+    world.insert_resource(Second(1));
+
+    let builder = ResourceGroupBuilder::new().init::<Log>().insert(Second(2));
+    assert_eq!(builder.len(), 2);
+    builder.apply(&mut world);
+
+    assert_eq!(world.resource::<Log>().0, ["existing"]);
+    assert_eq!(world.resource::<Second>().0, 2);
+}
+
+#[derive(Resource, Default)]
+struct Slot<const N: usize>(usize);
+
+macro_rules! push_slots {
+    ($builder:expr; $($init:literal)*; $($insert:literal)*) => {
+        $builder
+            $(.init::<Slot<$init>>())*
+            $(.insert(Slot::<$insert>($insert)))*
+    };
+}
+
+#[test]
+fn applies_more_operations_than_a_tuple_holds() {
+    let mut world = World::new();
+
+    let builder = push_slots!(
+        ResourceGroupBuilder::new();
+        0 1 2 3 4 5 6 7 8 9;
+        10 11 12 13 14 15 16 17 18 19
+    );
+    assert_eq!(builder.len(), 20);
+    let ids = builder.apply(&mut world);
+
+    assert_eq!(ids.len(), 20);
+    assert_eq!(world.components().resource_id::<Slot<0>>(), Some(ids[0]));
+    assert_eq!(world.components().resource_id::<Slot<19>>(), Some(ids[19]));
+    assert_eq!(world.resource::<Slot<5>>().0, 0);
+    assert_eq!(world.resource::<Slot<17>>().0, 17);
+}
@@ -0,0 +1,75 @@
+//! Tests for the forms accepted by the `resources!` macro.
+
+use bevy_app::App;
+use bevy_ecs::{prelude::*, system::CommandQueue};
+use bevy_proto_resource_tuples::resources;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource, Default)]
+struct Lives;
+
+#[derive(Resource)]
+struct Gravity(f32);
+
+#[derive(Resource)]
+struct Wind(f32);
+
+#[test]
+fn tuple_form_inits_and_inserts() {
+    let mut world = World::new();
+    resources!(world, init: (Score, Lives), insert: (Gravity(9.8), Wind(2.0)),);
+
+    assert!(world.contains_resource::<Score>());
+    assert!(world.contains_resource::<Lives>());
+    assert_eq!(world.resource::<Gravity>().0, 9.8);
+    assert_eq!(world.resource::<Wind>().0, 2.0);
+}
+
+#[test]
+fn list_form_inits_and_inserts() {
+    let mut world = World::new();
+    resources!(world; init Score, Lives; insert Gravity(9.8), Wind(2.0));
+
+    assert!(world.contains_resource::<Score>());
+    assert!(world.contains_resource::<Lives>());
+    assert_eq!(world.resource::<Gravity>().0, 9.8);
+    assert_eq!(world.resource::<Wind>().0, 2.0);
+}
+
+#[test]
+fn list_form_accepts_single_resources() {
+    let mut world = World::new();
+    resources!(world; init Score);
+    resources!(world; insert Gravity(1.6););
+
+    assert!(world.contains_resource::<Score>());
+    assert!(!world.contains_resource::<Lives>());
+    assert_eq!(world.resource::<Gravity>().0, 1.6);
+}
+
+#[test]
+fn list_form_inits_before_inserting() {
+    let mut world = World::new();
+    resources!(world; init Score; insert Score(10));
+
+    assert_eq!(world.resource::<Score>().0, 10);
+}
+
+#[test]
+fn list_form_works_on_app_and_commands() {
+    let mut app = App::new();
+    resources!(app; init Score; insert Wind(2.0));
+    assert!(app.world.contains_resource::<Score>());
+    assert_eq!(app.world.resource::<Wind>().0, 2.0);
+
+    let mut world = World::new();
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &world);
+    resources!(commands; init Lives; insert Gravity(9.8));
+    queue.apply(&mut world);
+
+    assert!(world.contains_resource::<Lives>());
+    assert_eq!(world.resource::<Gravity>().0, 9.8);
+}
@@ -0,0 +1,54 @@
+//! Tests for inserting fallibly constructed resources.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource, Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Resource, Debug, PartialEq)]
+struct Workers(usize);
+
+#[derive(Resource, Debug, PartialEq)]
+struct Host(&'static str);
+
+#[test]
+fn err_in_second_element_inserts_nothing() {
+    let mut world = World::new();
+    world.insert_resource(Port(80));
+
+    let result = world.insert_result_resources((
+        Ok::<_, &str>(Port(8080)),
+        Err::<Workers, _>("no workers"),
+        Ok(Host("localhost")),
+    ));
+
+    assert_eq!(result, Err("no workers"));
+    // The first element isn't inserted, so the existing value is untouched.
+    assert_eq!(world.resource::<Port>(), &Port(80));
+    assert!(!world.contains_resource::<Workers>());
+    assert!(!world.contains_resource::<Host>());
+}
+
+#[test]
+fn first_err_is_returned() {
+    let mut world = World::new();
+    let result = world.insert_result_resources((
+        Ok::<Port, &str>(Port(8080)),
+        Err::<Workers, _>("first"),
+        Err::<Host, _>("second"),
+    ));
+    assert_eq!(result, Err("first"));
+}
+
+#[test]
+fn all_ok_inserts_everything() {
+    let mut world = World::new();
+    let ids = world
+        .insert_result_resources((Ok::<_, ()>(Port(8080)), Ok(Workers(4))))
+        .unwrap();
+
+    assert_eq!(ids[0], world.components().resource_id::<Port>().unwrap());
+    assert_eq!(world.resource::<Port>(), &Port(8080));
+    assert_eq!(world.resource::<Workers>(), &Workers(4));
+}
@@ -0,0 +1,46 @@
+//! Tests for initializing resources from a system and piping the ids onwards.
+
+use bevy::{ecs::component::ComponentId, prelude::*};
+use bevy_proto_resource_tuples::init_resources_system;
+
+#[derive(Resource, Default)]
+struct Score;
+
+#[derive(Resource, Default)]
+struct Lives;
+
+#[derive(Resource, Default)]
+struct Log(Vec<ComponentId>);
+
+fn log_ids(In(ids): In<[ComponentId; 2]>, mut log: ResMut<Log>) {
+    log.0.extend(ids);
+}
+
+#[test]
+fn pipe_ids_into_logging_system() {
+    let mut app = App::new();
+    app.init_resource::<Log>()
+        .add_startup_system(init_resources_system::<(Score, Lives)>().pipe(log_ids));
+    app.update();
+
+    let components = app.world.components();
+    assert_eq!(
+        app.world.resource::<Log>().0,
+        [
+            components.resource_id::<Score>().unwrap(),
+            components.resource_id::<Lives>().unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn run_directly_on_world() {
+    let mut world = World::new();
+    let mut system = init_resources_system::<(Score, Lives)>();
+    system.initialize(&mut world);
+
+    let [score, _] = system.run((), &mut world);
+
+    assert_eq!(world.components().resource_id::<Score>(), Some(score));
+    assert!(world.contains_resource::<Lives>());
+}
@@ -0,0 +1,38 @@
+//! Tests for initializing resources with an explicit changed tick.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, Chain};
+
+#[derive(Resource, Default)]
+struct Score;
+
+#[derive(Resource, Default)]
+struct Lives;
+
+#[test]
+fn stamps_chained_and_existing_resources() {
+    let mut world = World::new();
+    world.init_resource::<Score>();
+    for _ in 0..10 {
+        world.increment_change_tick();
+    }
+
+    world.init_resources_at_tick::<Chain<(Score,), (Lives,)>>(3);
+
+    assert_eq!(world.resource_mut::<Score>().last_changed(), 3);
+    assert_eq!(world.resource_mut::<Lives>().last_changed(), 3);
+}
+
+#[test]
+fn stamped_tick_is_independent_of_world_age() {
+    let changed_at = |warmup: usize| {
+        let mut world = World::new();
+        for _ in 0..warmup {
+            world.increment_change_tick();
+        }
+        world.init_resources_at_tick::<(Score,)>(42);
+        world.resource_mut::<Score>().last_changed()
+    };
+
+    assert_eq!(changed_at(0), changed_at(100));
+}
@@ -0,0 +1,9 @@
+//! Compile-fail tests checking that invalid resource tuples are rejected with clear errors.
+
+#[test]
+// The expected errors list other `Resource` types, which changes when optional dependencies add more.
+#[cfg_attr(any(feature = "asset", feature = "diagnostic"), ignore)]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,13 @@
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Resource)]
+struct Seed(u64);
+
+fn main() {
+    let mut world = World::new();
+    world.init_resources::<(Score, Seed)>();
+}
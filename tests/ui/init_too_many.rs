@@ -0,0 +1,18 @@
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+macro_rules! resources {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Resource, Default)]
+            struct $name;
+        )*
+    };
+}
+
+resources!(R0, R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11, R12, R13, R14, R15, R16);
+
+fn main() {
+    let mut world = World::new();
+    world.init_resources::<(R0, R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11, R12, R13, R14, R15, R16)>();
+}
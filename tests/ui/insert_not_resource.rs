@@ -0,0 +1,12 @@
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::prelude::*;
+
+#[derive(Resource)]
+struct Score(u32);
+
+struct NotAResource(u32);
+
+fn main() {
+    let mut world = World::new();
+    world.insert_resources((Score(0), NotAResource(1)));
+}
@@ -0,0 +1,89 @@
+//! Tests for visiting groups of resources through a shared trait.
+
+use bevy_ecs::prelude::*;
+use bevy_proto_resource_tuples::{prelude::*, VisitResource, VisitResourceMut};
+
+trait Counter {
+    fn count(&self) -> u32;
+    fn reset(&mut self);
+}
+
+#[derive(Resource)]
+struct Kills(u32);
+
+#[derive(Resource)]
+struct Deaths(u32);
+
+impl Counter for Kills {
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+impl Counter for Deaths {
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+struct Sum(u32);
+
+impl<R: Counter> VisitResource<R> for Sum {
+    fn visit(&mut self, resource: &R) {
+        self.0 += resource.count();
+    }
+}
+
+/// Resets the counters that are above a threshold, leaving the others unchanged.
+struct ResetAbove(u32);
+
+impl<R: Resource + Counter> VisitResourceMut<R> for ResetAbove {
+    fn visit_mut(&mut self, mut resource: Mut<R>) {
+        if resource.count() > self.0 {
+            resource.reset();
+        }
+    }
+}
+
+#[test]
+fn visits_resources_in_order() {
+    let mut world = World::new();
+    world.insert_resources((Kills(5), Deaths(2)));
+
+    let mut sum = Sum(0);
+    world.for_each_resource::<(Kills, Deaths), _>(&mut sum);
+
+    assert_eq!(sum.0, 7);
+}
+
+#[test]
+fn skips_missing_resources() {
+    let mut world = World::new();
+    world.insert_resource(Deaths(2));
+
+    let mut sum = Sum(0);
+    world.for_each_resource::<(Kills, Deaths), _>(&mut sum);
+
+    assert_eq!(sum.0, 2);
+}
+
+#[test]
+fn visits_mutably_with_change_detection() {
+    let mut world = World::new();
+    world.insert_resources((Kills(5), Deaths(2)));
+    world.clear_trackers();
+
+    world.for_each_resource_mut::<(Kills, Deaths), _>(&mut ResetAbove(3));
+
+    assert_eq!(world.resource::<Kills>().0, 0);
+    assert_eq!(world.resource::<Deaths>().0, 2);
+    assert_eq!(world.resources_changed::<(Kills, Deaths)>(), [true, false]);
+}